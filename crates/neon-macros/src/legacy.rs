@@ -79,3 +79,17 @@ pub(crate) fn main(
     )
     .into()
 }
+
+pub(crate) fn class(
+    _attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::ItemImpl);
+
+    syn::Error::new_spanned(
+        &input.self_ty,
+        "#[neon::class] is only supported by the N-API backend",
+    )
+    .to_compile_error()
+    .into()
+}
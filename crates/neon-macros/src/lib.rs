@@ -42,3 +42,61 @@ pub fn main(
 ) -> proc_macro::TokenStream {
     macros::main(attr, item)
 }
+
+#[proc_macro_attribute]
+/// Defines a JavaScript class from the methods of an `impl` block, implementing
+/// [`Class`](https://docs.rs/neon/latest/neon/object/trait.Class.html) for the type.
+/// Only supported by the N-API backend.
+///
+/// Instances of the class wrap the Rust value. Methods are exposed according to
+/// their signature and `#[neon(...)]` attributes:
+///
+/// * `#[neon(constructor)]` marks the constructor, `fn(FunctionContext) -> NeonResult<Self>`.
+///   Exactly one constructor is required.
+/// * Methods taking `&self` or `&mut self` are instance methods on the prototype,
+///   `fn(&self, FunctionContext) -> JsResult<V>`. Since the result borrows from the
+///   context and not `self`, the lifetime must be explicit:
+///   `fn greet<'a>(&self, cx: FunctionContext<'a>) -> JsResult<'a, JsString>`.
+/// * Methods without a receiver are static methods on the constructor,
+///   `fn(FunctionContext) -> JsResult<V>`.
+/// * `#[neon(getter)]` and `#[neon(setter)]` define an accessor property. A getter is
+///   `fn(&self, FunctionContext) -> JsResult<V>` and a setter is
+///   `fn(&mut self, FunctionContext) -> NeonResult<()>`, receiving the value as the
+///   first argument. A `set_` prefix is removed from the name of a setter.
+/// * `#[neon(name = "...")]` overrides the JavaScript name of a property.
+/// * `#[neon(skip)]` excludes a method from the class.
+///
+/// ```ignore
+/// struct Counter {
+///     count: f64,
+/// }
+///
+/// impl Finalize for Counter {}
+///
+/// #[neon::class]
+/// impl Counter {
+///     #[neon(constructor)]
+///     fn new(_cx: FunctionContext) -> NeonResult<Self> {
+///         Ok(Counter { count: 0.0 })
+///     }
+///
+///     fn increment<'a>(&mut self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsNumber> {
+///         self.count += 1.0;
+///         Ok(cx.number(self.count))
+///     }
+///
+///     #[neon(getter)]
+///     fn count<'a>(&self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsNumber> {
+///         Ok(cx.number(self.count))
+///     }
+/// }
+/// ```
+///
+/// Borrowing rules are checked dynamically; calling a `&mut self` method while
+/// another method of the same instance is executing throws an exception.
+pub fn class(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    macros::class(attr, item)
+}
@@ -1,3 +1,7 @@
+mod class;
+
+pub(crate) use class::class;
+
 pub(crate) fn main(
     _attr: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
@@ -0,0 +1,363 @@
+//! Lowering of `#[neon::class]` to `napi_define_class`
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+enum Kind {
+    Constructor,
+    Method,
+    Getter,
+    Setter,
+    Static,
+}
+
+struct Member {
+    kind: Kind,
+    ident: syn::Ident,
+    // JavaScript name of the property
+    name: String,
+    // `true` if the receiver is `&mut self`
+    is_mut: bool,
+}
+
+struct Accessor {
+    name: String,
+    getter: Option<syn::Ident>,
+    setter: Option<syn::Ident>,
+}
+
+pub(crate) fn class(
+    _attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut input = syn::parse_macro_input!(item as syn::ItemImpl);
+
+    match expand(&mut input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &mut syn::ItemImpl) -> syn::Result<TokenStream> {
+    if let Some((_, path, _)) = &input.trait_ {
+        return Err(syn::Error::new(
+            path.span(),
+            "#[neon::class] must be applied to an inherent impl block",
+        ));
+    }
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "#[neon::class] does not support generic types",
+        ));
+    }
+
+    let self_ty = &input.self_ty;
+    let class_name = match &**self_ty {
+        syn::Type::Path(path) => path.path.segments.last().unwrap().ident.to_string(),
+        ty => {
+            return Err(syn::Error::new(
+                ty.span(),
+                "#[neon::class] must be applied to a named type",
+            ))
+        }
+    };
+
+    let mut members = Vec::new();
+
+    for item in input.items.iter_mut() {
+        if let syn::ImplItem::Method(method) = item {
+            if let Some(member) = parse_member(method)? {
+                members.push(member);
+            }
+        }
+    }
+
+    let mut constructor = None;
+    let mut wrappers = Vec::new();
+    let mut properties = Vec::new();
+    let mut accessors: Vec<Accessor> = Vec::new();
+
+    for member in members {
+        let ident = &member.ident;
+        let wrapper = format_ident!("__neon_class_{}", ident);
+        let name = &member.name;
+        let borrow = if member.is_mut {
+            quote!(&mut *this.borrow_mut())
+        } else {
+            quote!(&*this.borrow())
+        };
+
+        match member.kind {
+            Kind::Constructor => {
+                if constructor.is_some() {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "#[neon::class] may only have one constructor",
+                    ));
+                }
+
+                constructor = Some(ident.clone());
+                continue;
+            }
+            Kind::Method => {
+                wrappers.push(quote!(
+                    fn #wrapper(
+                        mut cx: ::neon::context::FunctionContext,
+                    ) -> ::neon::result::JsResult<::neon::types::JsValue> {
+                        let this = ::neon::macro_internal::class::this::<#self_ty>(&mut cx)?;
+
+                        <#self_ty>::#ident(#borrow, cx).map(|v| v.upcast())
+                    }
+                ));
+
+                properties.push(quote!(
+                    ::neon::macro_internal::class::Property::Method(#name, #wrapper)
+                ));
+            }
+            Kind::Static => {
+                wrappers.push(quote!(
+                    fn #wrapper(
+                        cx: ::neon::context::FunctionContext,
+                    ) -> ::neon::result::JsResult<::neon::types::JsValue> {
+                        <#self_ty>::#ident(cx).map(|v| v.upcast())
+                    }
+                ));
+
+                properties.push(quote!(
+                    ::neon::macro_internal::class::Property::Static(#name, #wrapper)
+                ));
+            }
+            Kind::Getter | Kind::Setter => {
+                let is_getter = matches!(member.kind, Kind::Getter);
+
+                if is_getter {
+                    wrappers.push(quote!(
+                        fn #wrapper(
+                            mut cx: ::neon::context::FunctionContext,
+                        ) -> ::neon::result::JsResult<::neon::types::JsValue> {
+                            let this = ::neon::macro_internal::class::this::<#self_ty>(&mut cx)?;
+
+                            <#self_ty>::#ident(#borrow, cx).map(|v| v.upcast())
+                        }
+                    ));
+                } else {
+                    wrappers.push(quote!(
+                        fn #wrapper(
+                            mut cx: ::neon::context::FunctionContext,
+                        ) -> ::neon::result::NeonResult<()> {
+                            let this = ::neon::macro_internal::class::this::<#self_ty>(&mut cx)?;
+
+                            <#self_ty>::#ident(#borrow, cx).map(|_| ())
+                        }
+                    ));
+                }
+
+                let accessor = match accessors.iter_mut().find(|a| &a.name == name) {
+                    Some(accessor) => accessor,
+                    None => {
+                        accessors.push(Accessor {
+                            name: name.clone(),
+                            getter: None,
+                            setter: None,
+                        });
+                        accessors.last_mut().unwrap()
+                    }
+                };
+
+                let slot = if is_getter {
+                    &mut accessor.getter
+                } else {
+                    &mut accessor.setter
+                };
+
+                if slot.is_some() {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("duplicate accessor for property `{}`", name),
+                    ));
+                }
+
+                *slot = Some(wrapper);
+            }
+        }
+    }
+
+    for Accessor {
+        name,
+        getter,
+        setter,
+    } in accessors
+    {
+        let getter = option(getter);
+        let setter = option(setter);
+
+        properties.push(quote!(
+            ::neon::macro_internal::class::Property::Accessor(#name, #getter, #setter)
+        ));
+    }
+
+    let constructor = constructor.ok_or_else(|| {
+        syn::Error::new(
+            Span::call_site(),
+            "#[neon::class] requires a method marked with #[neon(constructor)]",
+        )
+    })?;
+
+    Ok(quote!(
+        #input
+
+        impl ::neon::object::Class for #self_ty {
+            fn constructor<'a, C: ::neon::context::Context<'a>>(
+                cx: &mut C,
+            ) -> ::neon::result::JsResult<'a, ::neon::types::JsFunction> {
+                fn __neon_class_constructor(
+                    cx: ::neon::context::FunctionContext,
+                ) -> ::neon::result::JsResult<::neon::types::JsObject> {
+                    ::neon::macro_internal::class::construct(cx, <#self_ty>::#constructor)
+                }
+
+                #(#wrappers)*
+
+                static PROPERTIES: &[::neon::macro_internal::class::Property] = &[
+                    #(#properties,)*
+                ];
+
+                ::neon::macro_internal::class::define::<Self, _>(
+                    cx,
+                    #class_name,
+                    __neon_class_constructor,
+                    PROPERTIES,
+                )
+            }
+        }
+    ))
+}
+
+fn option(ident: Option<syn::Ident>) -> TokenStream {
+    match ident {
+        Some(ident) => quote!(::std::option::Option::Some(#ident)),
+        None => quote!(::std::option::Option::None),
+    }
+}
+
+// Parses and removes the `#[neon(...)]` attributes of a method. Returns `None` if
+// the method is marked with `#[neon(skip)]`.
+fn parse_member(method: &mut syn::ImplItemMethod) -> syn::Result<Option<Member>> {
+    let mut kind = None;
+    let mut name = None;
+    let mut skip = false;
+    let mut attrs = Vec::new();
+
+    for attr in method.attrs.drain(..) {
+        if !attr.path.is_ident("neon") {
+            attrs.push(attr);
+            continue;
+        }
+
+        let list = match attr.parse_meta()? {
+            syn::Meta::List(list) => list,
+            meta => return Err(syn::Error::new(meta.span(), "expected #[neon(...)]")),
+        };
+
+        for nested in list.nested {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                    let value = if path.is_ident("constructor") {
+                        Kind::Constructor
+                    } else if path.is_ident("getter") {
+                        Kind::Getter
+                    } else if path.is_ident("setter") {
+                        Kind::Setter
+                    } else if path.is_ident("skip") {
+                        skip = true;
+                        continue;
+                    } else {
+                        return Err(syn::Error::new(
+                            path.span(),
+                            "expected one of `constructor`, `getter`, `setter`, `skip` or `name`",
+                        ));
+                    };
+
+                    if kind.replace(value).is_some() {
+                        return Err(syn::Error::new(
+                            path.span(),
+                            "a method may only have one kind",
+                        ));
+                    }
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(lit),
+                    ..
+                })) if path.is_ident("name") => {
+                    name = Some(lit.value());
+                }
+                nested => {
+                    return Err(syn::Error::new(
+                        nested.span(),
+                        "expected one of `constructor`, `getter`, `setter`, `skip` or `name`",
+                    ))
+                }
+            }
+        }
+    }
+
+    method.attrs = attrs;
+
+    if skip {
+        return Ok(None);
+    }
+
+    let ident = method.sig.ident.clone();
+    let receiver = match method.sig.inputs.first() {
+        Some(syn::FnArg::Receiver(receiver)) => Some(receiver),
+        _ => None,
+    };
+
+    if let Some(receiver) = receiver {
+        if receiver.reference.is_none() {
+            return Err(syn::Error::new(
+                receiver.span(),
+                "#[neon::class] methods must take `&self` or `&mut self`",
+            ));
+        }
+    }
+
+    let is_mut = receiver.map(|r| r.mutability.is_some()).unwrap_or(false);
+    let kind = match (kind, receiver.is_some()) {
+        (Some(Kind::Constructor), true) => {
+            return Err(syn::Error::new(
+                method.sig.span(),
+                "a constructor must not take `self`",
+            ))
+        }
+        (Some(Kind::Getter), false) | (Some(Kind::Setter), false) => {
+            return Err(syn::Error::new(
+                method.sig.span(),
+                "an accessor must take `&self` or `&mut self`",
+            ))
+        }
+        (Some(kind), _) => kind,
+        (None, true) => Kind::Method,
+        (None, false) => Kind::Static,
+    };
+
+    let name = name.unwrap_or_else(|| {
+        let name = ident.to_string();
+
+        match kind {
+            Kind::Setter => name.strip_prefix("set_").unwrap_or(&name).to_string(),
+            _ => name,
+        }
+    });
+
+    Ok(Some(Member {
+        kind,
+        ident,
+        name,
+        is_mut,
+    }))
+}
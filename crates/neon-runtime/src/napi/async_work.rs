@@ -0,0 +1,160 @@
+//! Rust wrappers for N-API async work
+//!
+//! Async work executes a function on the libuv threadpool and completes on the
+//! JavaScript thread.
+//!
+//! See: [Simple Asynchronous Operations in N-API](https://nodejs.org/api/n-api.html#n_api_simple_asynchronous_operations)
+
+use std::any::Any;
+use std::ffi::c_void;
+use std::mem::{self, MaybeUninit};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+use crate::napi::bindings as napi;
+use crate::raw::{AsyncWork, Env};
+
+/// Function executed on the libuv threadpool
+pub type Execute<I, O> = fn(input: I) -> O;
+
+/// Function executed on the JavaScript thread after `Execute` has finished
+/// or the work has been cancelled
+pub type Complete<O, D> = fn(env: Env, output: Output<O>, data: D);
+
+/// Outcome of an async work item, passed to the `Complete` callback
+pub enum Output<O> {
+    /// The `Execute` callback returned a value
+    Value(O),
+    /// The `Execute` callback panicked with the contained payload
+    Panic(Box<dyn Any + Send + 'static>),
+    /// The work was cancelled before it started executing, or otherwise completed
+    /// without executing
+    Cancelled,
+}
+
+enum State<I, O> {
+    Input(I),
+    Executing,
+    Output(std::thread::Result<O>),
+}
+
+struct Data<I, O, D> {
+    state: State<I, O>,
+    execute: Execute<I, O>,
+    complete: Complete<O, D>,
+    data: D,
+    work: AsyncWork,
+}
+
+/// Schedules work to execute on the libuv threadpool
+///
+/// `execute` is called on a threadpool thread with `input`. Afterward, `complete`
+/// is called on the JavaScript thread with the output and `data`. Panics in
+/// `execute` are caught and passed to `complete`.
+///
+/// The returned `AsyncWork` may be passed to [`cancel`](cancel) until `complete`
+/// has been called; afterward, it is no longer valid.
+///
+/// # Safety
+/// * `env` must be a valid `napi_env` for the current thread
+pub unsafe fn schedule<I, O, D>(
+    env: Env,
+    input: I,
+    execute: Execute<I, O>,
+    complete: Complete<O, D>,
+    data: D,
+) -> AsyncWork
+where
+    I: Send + 'static,
+    O: Send + 'static,
+    D: 'static,
+{
+    let data = Box::into_raw(Box::new(Data {
+        state: State::Input(input),
+        execute,
+        complete,
+        data,
+        work: ptr::null_mut(),
+    }));
+
+    let name = "neon_async_work";
+    let mut resource_name = MaybeUninit::uninit();
+    let mut work = MaybeUninit::uninit();
+
+    assert_eq!(
+        napi::create_string_utf8(
+            env,
+            name.as_ptr() as *const _,
+            name.len(),
+            resource_name.as_mut_ptr(),
+        ),
+        napi::Status::Ok,
+    );
+
+    assert_eq!(
+        napi::create_async_work(
+            env,
+            ptr::null_mut(),
+            resource_name.assume_init(),
+            Some(call_execute::<I, O, D>),
+            Some(call_complete::<I, O, D>),
+            data.cast(),
+            work.as_mut_ptr(),
+        ),
+        napi::Status::Ok,
+    );
+
+    let work = work.assume_init();
+
+    (*data).work = work;
+
+    assert_eq!(napi::queue_async_work(env, work), napi::Status::Ok);
+
+    work
+}
+
+/// Attempts to cancel work that has not started executing. Returns `true` if the
+/// work was cancelled; `complete` will be called with `Output::Cancelled`.
+///
+/// # Safety
+/// * `env` must be a valid `napi_env` for the current thread
+/// * `work` must not have been completed
+pub unsafe fn cancel(env: Env, work: AsyncWork) -> bool {
+    napi::cancel_async_work(env, work) == napi::Status::Ok
+}
+
+unsafe extern "C" fn call_execute<I, O, D>(_: Env, data: *mut c_void) {
+    let data = &mut *data.cast::<Data<I, O, D>>();
+
+    // `execute` is only called once, replacing the input
+    if let State::Input(input) = mem::replace(&mut data.state, State::Executing) {
+        let execute = data.execute;
+        let output = catch_unwind(AssertUnwindSafe(move || execute(input)));
+
+        data.state = State::Output(output);
+    }
+}
+
+unsafe extern "C" fn call_complete<I, O, D>(env: Env, status: napi::Status, data: *mut c_void) {
+    let Data {
+        state,
+        complete,
+        data,
+        work,
+        ..
+    } = *Box::<Data<I, O, D>>::from_raw(data.cast());
+
+    assert_eq!(napi::delete_async_work(env, work), napi::Status::Ok);
+
+    // Panicking here would abort the process. If the work did not execute for any
+    // other reason than cancellation, `complete` still needs to be called to release
+    // `data`; report it as cancelled.
+    let output = match (status, state) {
+        (napi::Status::Cancelled, _) => Output::Cancelled,
+        (_, State::Output(Ok(output))) => Output::Value(output),
+        (_, State::Output(Err(panic))) => Output::Panic(panic),
+        (_, _) => Output::Cancelled,
+    };
+
+    complete(env, output, data);
+}
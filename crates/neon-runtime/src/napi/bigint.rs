@@ -0,0 +1,113 @@
+//! Facilities for working with JavaScript BigInt values.
+//!
+//! See: [BigInt in N-API](https://nodejs.org/api/n-api.html#n_api_napi_create_bigint_int64)
+
+use std::mem::MaybeUninit;
+
+use crate::napi::bindings as napi;
+use crate::raw::{Env, Local};
+
+/// Create a BigInt from an `i64`
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+pub unsafe fn new_i64(env: Env, value: i64) -> Local {
+    let mut local = MaybeUninit::zeroed();
+    let status = napi::create_bigint_int64(env, value, local.as_mut_ptr());
+    assert_eq!(status, napi::Status::Ok);
+    local.assume_init()
+}
+
+/// Create a BigInt from a `u64`
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+pub unsafe fn new_u64(env: Env, value: u64) -> Local {
+    let mut local = MaybeUninit::zeroed();
+    let status = napi::create_bigint_uint64(env, value, local.as_mut_ptr());
+    assert_eq!(status, napi::Status::Ok);
+    local.assume_init()
+}
+
+/// Mutates the `out` argument to refer to a BigInt created from a sign and a
+/// little-endian sequence of 64-bit words. Returns `false` if the value couldn't
+/// be created, e.g., because it is too large; an exception will be pending.
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+pub unsafe fn new_words(out: &mut Local, env: Env, negative: bool, words: &[u64]) -> bool {
+    let status = napi::create_bigint_words(
+        env,
+        negative as i32,
+        words.len(),
+        words.as_ptr(),
+        out as *mut Local,
+    );
+
+    status == napi::Status::Ok
+}
+
+/// Get the value of a BigInt as an `i64`, and whether the conversion was lossless
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `value` is a BigInt
+pub unsafe fn get_i64(env: Env, value: Local) -> (i64, bool) {
+    let mut result = 0;
+    let mut lossless = false;
+    let status = napi::get_value_bigint_int64(env, value, &mut result, &mut lossless);
+    assert_eq!(status, napi::Status::Ok);
+    (result, lossless)
+}
+
+/// Get the value of a BigInt as a `u64`, and whether the conversion was lossless
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `value` is a BigInt
+pub unsafe fn get_u64(env: Env, value: Local) -> (u64, bool) {
+    let mut result = 0;
+    let mut lossless = false;
+    let status = napi::get_value_bigint_uint64(env, value, &mut result, &mut lossless);
+    assert_eq!(status, napi::Status::Ok);
+    (result, lossless)
+}
+
+/// Get the number of 64-bit words required to represent the magnitude of a BigInt
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `value` is a BigInt
+pub unsafe fn word_count(env: Env, value: Local) -> usize {
+    let mut word_count = 0;
+    let status = napi::get_value_bigint_words(
+        env,
+        value,
+        std::ptr::null_mut(),
+        &mut word_count,
+        std::ptr::null_mut(),
+    );
+    assert_eq!(status, napi::Status::Ok);
+    word_count
+}
+
+/// Fills `words` with the little-endian magnitude of a BigInt, truncating if
+/// `words` is too short. Returns whether the BigInt is negative and the number of
+/// words required to represent it.
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `value` is a BigInt
+pub unsafe fn get_words(env: Env, value: Local, words: &mut [u64]) -> (bool, usize) {
+    let mut sign_bit = 0;
+    let mut word_count = words.len();
+    let status = napi::get_value_bigint_words(
+        env,
+        value,
+        &mut sign_bit,
+        &mut word_count,
+        words.as_mut_ptr(),
+    );
+    assert_eq!(status, napi::Status::Ok);
+    (sign_bit != 0, word_count)
+}
@@ -16,12 +16,26 @@ mod napi1 {
 
             fn create_double(env: Env, value: f64, result: *mut Value) -> Status;
 
+            fn create_int32(env: Env, value: i32, result: *mut Value) -> Status;
+
+            fn create_uint32(env: Env, value: u32, result: *mut Value) -> Status;
+
+            fn create_int64(env: Env, value: i64, result: *mut Value) -> Status;
+
+            fn create_symbol(env: Env, description: Value, result: *mut Value) -> Status;
+
             fn create_object(env: Env, result: *mut Value) -> Status;
 
             fn get_value_bool(env: Env, value: Value, result: *mut bool) -> Status;
 
             fn get_value_double(env: Env, value: Value, result: *mut f64) -> Status;
 
+            fn get_value_int32(env: Env, value: Value, result: *mut i32) -> Status;
+
+            fn get_value_uint32(env: Env, value: Value, result: *mut u32) -> Status;
+
+            fn get_value_int64(env: Env, value: Value, result: *mut i64) -> Status;
+
             fn create_array_with_length(env: Env, length: usize, result: *mut Value) -> Status;
 
             fn get_array_length(env: Env, value: Value, result: *mut u32) -> Status;
@@ -32,6 +46,10 @@ mod napi1 {
 
             fn coerce_to_string(env: Env, value: Value, result: *mut Value) -> Status;
 
+            fn coerce_to_number(env: Env, value: Value, result: *mut Value) -> Status;
+
+            fn coerce_to_bool(env: Env, value: Value, result: *mut Value) -> Status;
+
             fn throw(env: Env, error: Value) -> Status;
 
             fn create_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
@@ -54,6 +72,8 @@ mod napi1 {
 
             fn is_arraybuffer(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_buffer(env: Env, value: Value, result: *mut bool) -> Status;
+            fn is_typedarray(env: Env, value: Value, result: *mut bool) -> Status;
+            fn is_dataview(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_error(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_array(env: Env, value: Value, result: *mut bool) -> Status;
 
@@ -65,6 +85,22 @@ mod napi1 {
                 result: *mut usize,
             ) -> Status;
 
+            fn get_value_string_utf16(
+                env: Env,
+                value: Value,
+                buf: *mut u16,
+                bufsize: usize,
+                result: *mut usize,
+            ) -> Status;
+
+            fn get_value_string_latin1(
+                env: Env,
+                value: Value,
+                buf: *mut c_char,
+                bufsize: usize,
+                result: *mut usize,
+            ) -> Status;
+
             fn create_type_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
 
             fn create_range_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
@@ -76,6 +112,20 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn create_string_utf16(
+                env: Env,
+                str: *const u16,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn create_string_latin1(
+                env: Env,
+                str: *const c_char,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
             fn create_arraybuffer(
                 env: Env,
                 byte_length: usize,
@@ -90,6 +140,42 @@ mod napi1 {
                 byte_length: *mut usize,
             ) -> Status;
 
+            fn create_typedarray(
+                env: Env,
+                type_: TypedArrayType,
+                length: usize,
+                arraybuffer: Value,
+                byte_offset: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_typedarray_info(
+                env: Env,
+                typedarray: Value,
+                type_: *mut TypedArrayType,
+                length: *mut usize,
+                data: *mut *mut c_void,
+                arraybuffer: *mut Value,
+                byte_offset: *mut usize,
+            ) -> Status;
+
+            fn create_dataview(
+                env: Env,
+                length: usize,
+                arraybuffer: Value,
+                byte_offset: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_dataview_info(
+                env: Env,
+                dataview: Value,
+                bytelength: *mut usize,
+                data: *mut *mut c_void,
+                arraybuffer: *mut Value,
+                byte_offset: *mut usize,
+            ) -> Status;
+
             fn create_buffer(
                 env: Env,
                 length: usize,
@@ -151,6 +237,14 @@ mod napi1 {
 
             fn get_property(env: Env, object: Value, key: Value, result: *mut Value) -> Status;
 
+            fn has_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn has_own_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn delete_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn get_prototype(env: Env, object: Value, result: *mut Value) -> Status;
+
             fn set_element(env: Env, object: Value, index: u32, value: Value) -> Status;
 
             fn get_element(env: Env, object: Value, index: u32, result: *mut Value) -> Status;
@@ -179,6 +273,9 @@ mod napi1 {
 
             fn strict_equals(env: Env, lhs: Value, rhs: Value, result: *mut bool) -> Status;
 
+            fn instanceof(env: Env, object: Value, constructor: Value, result: *mut bool)
+                -> Status;
+
             fn create_external_arraybuffer(
                 env: Env,
                 data: *mut c_void,
@@ -198,6 +295,72 @@ mod napi1 {
             ) -> Status;
 
             fn run_script(env: Env, script: Value, result: *mut Value) -> Status;
+
+            fn create_promise(env: Env, deferred: *mut Deferred, promise: *mut Value) -> Status;
+
+            fn resolve_deferred(env: Env, deferred: Deferred, resolution: Value) -> Status;
+
+            fn reject_deferred(env: Env, deferred: Deferred, rejection: Value) -> Status;
+
+            fn is_promise(env: Env, value: Value, is_promise: *mut bool) -> Status;
+
+            fn create_async_work(
+                env: Env,
+                async_resource: Value,
+                async_resource_name: Value,
+                execute: AsyncExecuteCallback,
+                complete: AsyncCompleteCallback,
+                data: *mut c_void,
+                result: *mut AsyncWork,
+            ) -> Status;
+
+            fn delete_async_work(env: Env, work: AsyncWork) -> Status;
+
+            fn queue_async_work(env: Env, work: AsyncWork) -> Status;
+
+            fn cancel_async_work(env: Env, work: AsyncWork) -> Status;
+
+            fn define_class(
+                env: Env,
+                utf8name: *const c_char,
+                length: usize,
+                constructor: Callback,
+                data: *mut c_void,
+                property_count: usize,
+                properties: *const PropertyDescriptor,
+                result: *mut Value,
+            ) -> Status;
+
+            fn define_properties(
+                env: Env,
+                object: Value,
+                property_count: usize,
+                properties: *const PropertyDescriptor,
+            ) -> Status;
+
+            fn wrap(
+                env: Env,
+                js_object: Value,
+                native_object: *mut c_void,
+                finalize_cb: Finalize,
+                finalize_hint: *mut c_void,
+                result: *mut Ref,
+            ) -> Status;
+
+            fn unwrap(env: Env, js_object: Value, result: *mut *mut c_void) -> Status;
+
+            fn remove_wrap(env: Env, js_object: Value, result: *mut *mut c_void) -> Status;
+        }
+    );
+}
+
+#[cfg(feature = "napi-3")]
+mod napi3 {
+    use super::super::types::*;
+
+    generate!(
+        extern "C" {
+            fn fatal_exception(env: Env, err: Value) -> Status;
         }
     );
 }
@@ -244,6 +407,7 @@ mod napi4 {
 #[cfg(feature = "napi-5")]
 mod napi5 {
     use super::super::types::*;
+    use std::os::raw::c_void;
 
     generate!(
         extern "C" {
@@ -252,6 +416,15 @@ mod napi5 {
             fn get_date_value(env: Env, value: Value, result: *mut f64) -> Status;
 
             fn is_date(env: Env, value: Value, result: *mut bool) -> Status;
+
+            fn add_finalizer(
+                env: Env,
+                js_object: Value,
+                finalize_data: *mut c_void,
+                finalize_cb: Finalize,
+                finalize_hint: *mut c_void,
+                result: *mut Ref,
+            ) -> Status;
         }
     );
 }
@@ -280,17 +453,68 @@ mod napi6 {
             ) -> Status;
 
             fn get_instance_data(env: Env, data: *mut *mut c_void) -> Status;
+
+            fn create_bigint_int64(env: Env, value: i64, result: *mut Value) -> Status;
+
+            fn create_bigint_uint64(env: Env, value: u64, result: *mut Value) -> Status;
+
+            fn create_bigint_words(
+                env: Env,
+                sign_bit: i32,
+                word_count: usize,
+                words: *const u64,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_value_bigint_int64(
+                env: Env,
+                value: Value,
+                result: *mut i64,
+                lossless: *mut bool,
+            ) -> Status;
+
+            fn get_value_bigint_uint64(
+                env: Env,
+                value: Value,
+                result: *mut u64,
+                lossless: *mut bool,
+            ) -> Status;
+
+            fn get_value_bigint_words(
+                env: Env,
+                value: Value,
+                sign_bit: *mut i32,
+                word_count: *mut usize,
+                words: *mut u64,
+            ) -> Status;
+        }
+    );
+}
+
+#[cfg(feature = "napi-8")]
+mod napi8 {
+    use super::super::types::*;
+
+    generate!(
+        extern "C" {
+            fn object_freeze(env: Env, object: Value) -> Status;
+
+            fn object_seal(env: Env, object: Value) -> Status;
         }
     );
 }
 
 pub(crate) use napi1::*;
+#[cfg(feature = "napi-3")]
+pub(crate) use napi3::*;
 #[cfg(feature = "napi-4")]
 pub(crate) use napi4::*;
 #[cfg(feature = "napi-5")]
 pub(crate) use napi5::*;
 #[cfg(feature = "napi-6")]
 pub(crate) use napi6::*;
+#[cfg(feature = "napi-8")]
+pub(crate) use napi8::*;
 
 use super::{Env, Status};
 
@@ -316,6 +540,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
 
     napi1::load(&host, version, 1)?;
 
+    #[cfg(feature = "napi-3")]
+    napi3::load(&host, version, 3)?;
+
     #[cfg(feature = "napi-4")]
     napi4::load(&host, version, 4)?;
 
@@ -325,5 +552,8 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
     #[cfg(feature = "napi-6")]
     napi6::load(&host, version, 6)?;
 
+    #[cfg(feature = "napi-8")]
+    napi8::load(&host, version, 8)?;
+
     Ok(())
 }
@@ -47,6 +47,22 @@ pub struct Ref__ {
 
 pub type Ref = *mut Ref__;
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Deferred__ {
+    _unused: [u8; 0],
+}
+
+pub type Deferred = *mut Deferred__;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AsyncWork__ {
+    _unused: [u8; 0],
+}
+
+pub type AsyncWork = *mut AsyncWork__;
+
 #[cfg(feature = "napi-4")]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -62,6 +78,11 @@ pub(crate) type Callback = Option<unsafe extern "C" fn(env: Env, info: CallbackI
 pub(crate) type Finalize =
     Option<unsafe extern "C" fn(env: Env, finalize_data: *mut c_void, finalize_hint: *mut c_void)>;
 
+pub type AsyncExecuteCallback = Option<unsafe extern "C" fn(env: Env, data: *mut c_void)>;
+
+pub type AsyncCompleteCallback =
+    Option<unsafe extern "C" fn(env: Env, status: Status, data: *mut c_void)>;
+
 #[cfg(feature = "napi-4")]
 pub type ThreadsafeFunctionCallJs = Option<
     unsafe extern "C" fn(env: Env, js_callback: Value, context: *mut c_void, data: *mut c_void),
@@ -111,6 +132,23 @@ pub(crate) enum ValueType {
     BigInt = 9,
 }
 
+#[allow(dead_code)]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TypedArrayType {
+    Int8Array = 0,
+    Uint8Array = 1,
+    Uint8ClampedArray = 2,
+    Int16Array = 3,
+    Uint16Array = 4,
+    Int32Array = 5,
+    Uint32Array = 6,
+    Float32Array = 7,
+    Float64Array = 8,
+    BigInt64Array = 9,
+    BigUint64Array = 10,
+}
+
 #[allow(dead_code)]
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -128,6 +166,7 @@ pub enum KeyConversion {
 }
 
 #[cfg(feature = "napi-4")]
+#[allow(dead_code)]
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ThreadsafeFunctionCallMode {
@@ -152,6 +191,7 @@ pub(crate) struct KeyFilter(pub ::std::os::raw::c_uint);
 impl KeyFilter {
     pub(crate) const ALL_PROPERTIES: KeyFilter = KeyFilter(0);
     pub(crate) const WRITABLE: KeyFilter = KeyFilter(1);
+    pub(crate) const ENUMERABLE: KeyFilter = KeyFilter(2);
     pub(crate) const CONFIGURABLE: KeyFilter = KeyFilter(4);
     pub(crate) const SKIP_STRINGS: KeyFilter = KeyFilter(8);
     pub(crate) const SKIP_SYMBOLS: KeyFilter = KeyFilter(16);
@@ -186,3 +226,44 @@ impl std::ops::BitAndAssign for KeyFilter {
         self.0 &= rhs.0;
     }
 }
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct PropertyAttributes(pub ::std::os::raw::c_int);
+
+#[allow(dead_code)]
+impl PropertyAttributes {
+    pub(crate) const DEFAULT: PropertyAttributes = PropertyAttributes(0);
+    pub(crate) const WRITABLE: PropertyAttributes = PropertyAttributes(1);
+    pub(crate) const ENUMERABLE: PropertyAttributes = PropertyAttributes(1 << 1);
+    pub(crate) const CONFIGURABLE: PropertyAttributes = PropertyAttributes(1 << 2);
+    pub(crate) const STATIC: PropertyAttributes = PropertyAttributes(1 << 10);
+}
+
+impl std::ops::BitOr<PropertyAttributes> for PropertyAttributes {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        PropertyAttributes(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for PropertyAttributes {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: PropertyAttributes) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct PropertyDescriptor {
+    pub utf8name: *const ::std::os::raw::c_char,
+    pub name: Value,
+    pub method: Callback,
+    pub getter: Callback,
+    pub setter: Callback,
+    pub value: Value,
+    pub attributes: PropertyAttributes,
+    pub data: *mut c_void,
+}
@@ -0,0 +1,40 @@
+//! Facilities for defining JavaScript classes.
+
+use std::os::raw::c_void;
+
+use crate::call::CCallback;
+use crate::napi::bindings as napi;
+use crate::property::Descriptor;
+use crate::raw::{Env, Local};
+
+/// Mutates the `out` argument to refer to the constructor of a newly defined class
+/// named `name`. Returns `false` if the class couldn't be defined.
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `properties` contain valid `napi_value` names and callbacks expecting their `data`
+pub unsafe fn define(
+    out: &mut Local,
+    env: Env,
+    name: &str,
+    constructor: CCallback,
+    properties: &[Descriptor],
+) -> bool {
+    let properties = properties
+        .iter()
+        .map(|property| property.to_napi())
+        .collect::<Vec<_>>();
+
+    let status = napi::define_class(
+        env,
+        name.as_ptr().cast(),
+        name.len(),
+        std::mem::transmute::<*mut c_void, napi::Callback>(constructor.static_callback),
+        constructor.dynamic_callback,
+        properties.len(),
+        properties.as_ptr(),
+        out as *mut Local,
+    );
+
+    status == napi::Status::Ok
+}
@@ -1,15 +1,34 @@
 use crate::napi::bindings as napi;
 use crate::raw::{Env, Local};
 
-/// This API is currently unused, see https://github.com/neon-bindings/neon/issues/572
+/// Mutates `out` to refer to the result of the abstract `ToObject` operation on
+/// `value`. Returns `false` if an exception was thrown, e.g., for `null`.
 pub unsafe fn to_object(out: &mut Local, env: Env, value: Local) -> bool {
     let status = napi::coerce_to_object(env, value, out as *mut _);
 
     status == napi::Status::Ok
 }
 
+/// Mutates `out` to refer to the result of the abstract `ToString` operation on
+/// `value`. Returns `false` if an exception was thrown, e.g., by `toString`.
 pub unsafe fn to_string(out: &mut Local, env: Env, value: Local) -> bool {
     let status = napi::coerce_to_string(env, value, out as *mut _);
 
     status == napi::Status::Ok
 }
+
+/// Mutates `out` to refer to the result of the abstract `ToNumber` operation on
+/// `value`. Returns `false` if an exception was thrown, e.g., by `valueOf`.
+pub unsafe fn to_number(out: &mut Local, env: Env, value: Local) -> bool {
+    let status = napi::coerce_to_number(env, value, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Mutates `out` to refer to the result of the abstract `ToBoolean` operation on
+/// `value`, which never throws.
+pub unsafe fn to_boolean(out: &mut Local, env: Env, value: Local) {
+    let status = napi::coerce_to_bool(env, value, out as *mut _);
+
+    assert_eq!(status, napi::Status::Ok);
+}
@@ -0,0 +1,68 @@
+//! Facilities for working with DataView objects over an ArrayBuffer.
+//!
+//! See: [DataView in N-API](https://nodejs.org/api/n-api.html#n_api_napi_create_dataview)
+
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+use std::ptr::null_mut;
+
+use crate::napi::bindings as napi;
+use crate::raw::{Env, Local};
+
+/// The layout of a DataView, as reported by `napi_get_dataview_info`
+#[derive(Debug, Clone, Copy)]
+pub struct DataViewInfo {
+    /// Pointer to the first byte of the view, already adjusted by `offset`
+    pub data: *mut c_void,
+    /// Size of the view, in bytes
+    pub size: usize,
+    /// The underlying ArrayBuffer
+    pub buf: Local,
+    /// Offset of the view in the ArrayBuffer, in bytes
+    pub offset: usize,
+}
+
+/// Mutates the `out` argument to refer to a new DataView of `size` bytes over
+/// `buf`, starting at `offset` bytes. Returns `false` if the view couldn't be
+/// created, e.g., because the range is out of bounds; a `RangeError` will be
+/// pending.
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `buf` is an ArrayBuffer
+pub unsafe fn new(out: &mut Local, env: Env, buf: Local, offset: usize, size: usize) -> bool {
+    let status = napi::create_dataview(env, size, buf, offset, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Get the layout of a DataView
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `value` is a DataView
+pub unsafe fn info(env: Env, value: Local) -> DataViewInfo {
+    let mut data = null_mut();
+    let mut size = 0;
+    let mut buf = MaybeUninit::uninit();
+    let mut offset = 0;
+
+    assert_eq!(
+        napi::get_dataview_info(
+            env,
+            value,
+            &mut size,
+            &mut data,
+            buf.as_mut_ptr(),
+            &mut offset
+        ),
+        napi::Status::Ok,
+    );
+
+    DataViewInfo {
+        data,
+        size,
+        buf: buf.assume_init(),
+        offset,
+    }
+}
@@ -48,27 +48,50 @@ pub unsafe fn throw(env: Env, val: Local) {
     assert_eq!(status, napi::Status::Ok);
 }
 
+/// Triggers an `uncaughtException` in JavaScript with `err`
+#[cfg(feature = "napi-3")]
+pub unsafe fn fatal_exception(env: Env, err: Local) {
+    let status = napi::fatal_exception(env, err);
+
+    assert_eq!(status, napi::Status::Ok);
+}
+
 pub unsafe fn new_error(env: Env, out: &mut Local, msg: Local) {
+    new_error_with_code(env, out, ptr::null_mut(), msg);
+}
+
+pub unsafe fn new_type_error(env: Env, out: &mut Local, msg: Local) {
+    new_type_error_with_code(env, out, ptr::null_mut(), msg);
+}
+
+pub unsafe fn new_range_error(env: Env, out: &mut Local, msg: Local) {
+    new_range_error_with_code(env, out, ptr::null_mut(), msg);
+}
+
+/// Creates an `Error` with a `code` property. The `code` may be null to omit it.
+pub unsafe fn new_error_with_code(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    let status = napi::create_error(env, ptr::null_mut(), msg, result.as_mut_ptr());
+    let status = napi::create_error(env, code, msg, result.as_mut_ptr());
 
     assert_eq!(status, napi::Status::Ok);
 
     *out = result.assume_init();
 }
 
-pub unsafe fn new_type_error(env: Env, out: &mut Local, msg: Local) {
+/// Creates a `TypeError` with a `code` property. The `code` may be null to omit it.
+pub unsafe fn new_type_error_with_code(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    let status = napi::create_type_error(env, ptr::null_mut(), msg, result.as_mut_ptr());
+    let status = napi::create_type_error(env, code, msg, result.as_mut_ptr());
 
     assert_eq!(status, napi::Status::Ok);
 
     *out = result.assume_init();
 }
 
-pub unsafe fn new_range_error(env: Env, out: &mut Local, msg: Local) {
+/// Creates a `RangeError` with a `code` property. The `code` may be null to omit it.
+pub unsafe fn new_range_error_with_code(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    let status = napi::create_range_error(env, ptr::null_mut(), msg, result.as_mut_ptr());
+    let status = napi::create_range_error(env, code, msg, result.as_mut_ptr());
 
     assert_eq!(status, napi::Status::Ok);
 
@@ -3,8 +3,9 @@ use std::mem::MaybeUninit;
 use crate::napi::bindings as napi;
 use crate::raw::{Env, Local};
 
-/// `finalize_external` is invoked immediately before a `napi_external` is garbage collected
-extern "C" fn finalize_external<T: Send + 'static>(
+/// `finalize_external` is invoked immediately before a `napi_external` (or an object
+/// wrapped with `napi_wrap`) is garbage collected
+pub(crate) extern "C" fn finalize_external<T: Send + 'static>(
     env: Env,
     // Raw pointer to a `Box<T>` stored by a `napi_external`
     data: *mut std::ffi::c_void,
@@ -22,6 +22,32 @@ pub unsafe fn new(out: &mut Local, env: Env, callback: CCallback) -> bool {
     status == napi::Status::Ok
 }
 
+/// Mutates the `out` argument provided to refer to a newly created `v8::Function` that
+/// owns the data pointed to by `callback.dynamic_callback`. `finalize` is called with
+/// that pointer immediately before the function is garbage collected, or immediately
+/// if the function couldn't be created, in which case `false` is returned.
+#[cfg(feature = "napi-5")]
+pub unsafe fn new_owned(
+    out: &mut Local,
+    env: Env,
+    callback: CCallback,
+    finalize: fn(*mut c_void),
+) -> bool {
+    let data = callback.dynamic_callback;
+
+    if !new(out, env, callback) {
+        finalize(data);
+        return false;
+    }
+
+    assert!(
+        crate::napi::object::add_finalizer(env, *out, data, finalize),
+        "Failed to add finalizer to function"
+    );
+
+    true
+}
+
 pub unsafe fn get_dynamic_callback(_env: Env, data: *mut c_void) -> *mut c_void {
     data
 }
@@ -9,3 +9,12 @@ pub unsafe fn strict_equals(env: Env, lhs: Local, rhs: Local) -> bool {
     );
     result
 }
+
+/// Mutates `out` to `true` if `object` is an instance of `constructor`, as with the
+/// `instanceof` operator. Returns `false` if the check threw an exception, e.g.,
+/// because `constructor` is not a function.
+pub unsafe fn instance_of(out: &mut bool, env: Env, object: Local, constructor: Local) -> bool {
+    let status = napi::instanceof(env, object, constructor, out as *mut _);
+
+    status == napi::Status::Ok
+}
@@ -1,8 +1,13 @@
 pub mod array;
 pub mod arraybuffer;
+pub mod async_work;
+#[cfg(feature = "napi-6")]
+pub mod bigint;
 pub mod buffer;
 pub mod call;
+pub mod class;
 pub mod convert;
+pub mod dataview;
 #[cfg(feature = "napi-5")]
 pub mod date;
 pub mod error;
@@ -13,6 +18,8 @@ pub mod lifecycle;
 pub mod mem;
 pub mod object;
 pub mod primitive;
+pub mod promise;
+pub mod property;
 pub mod raw;
 pub mod reference;
 pub mod scope;
@@ -20,6 +27,7 @@ pub mod string;
 pub mod tag;
 #[cfg(feature = "napi-4")]
 pub mod tsfn;
+pub mod typedarray;
 
 mod bindings;
 pub use bindings::*;
@@ -1,6 +1,9 @@
 use std::mem::MaybeUninit;
+#[cfg(feature = "napi-5")]
+use std::os::raw::c_void;
 
 use crate::napi::bindings as napi;
+use crate::property::Descriptor;
 use crate::raw::{Env, Local};
 
 /// Mutates the `out` argument to refer to a `napi_value` containing a newly created JavaScript Object.
@@ -9,17 +12,88 @@ pub unsafe fn new(out: &mut Local, env: Env) {
 }
 
 #[cfg(feature = "napi-6")]
-/// Mutates the `out` argument to refer to a `napi_value` containing the own property names of the
-/// `object` as a JavaScript Array.
+/// Selects the keys collected by [`get_all_property_names`](get_all_property_names).
+///
+/// Each filter flag excludes properties without the attribute; the default collects
+/// all own keys of an object, including symbols, with indices as numbers.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KeyOptions {
+    /// Include the keys of the prototype chain
+    pub include_prototypes: bool,
+    pub writable: bool,
+    pub enumerable: bool,
+    pub configurable: bool,
+    pub skip_strings: bool,
+    pub skip_symbols: bool,
+    /// Convert integer indices to strings
+    pub numbers_to_strings: bool,
+}
+
+#[cfg(feature = "napi-6")]
+/// Mutates the `out` argument to refer to a `napi_value` containing the own property names
+/// of the `object` as a JavaScript Array.
 pub unsafe fn get_own_property_names(out: &mut Local, env: Env, object: Local) -> bool {
+    let options = KeyOptions {
+        skip_symbols: true,
+        numbers_to_strings: true,
+        ..Default::default()
+    };
+
+    get_all_property_names(out, env, object, options)
+}
+
+#[cfg(feature = "napi-6")]
+/// Mutates the `out` argument to refer to a `napi_value` containing the property names
+/// of the `object` selected by `options` as a JavaScript Array. Returns `false` if an
+/// exception was thrown, e.g., by a proxy.
+pub unsafe fn get_all_property_names(
+    out: &mut Local,
+    env: Env,
+    object: Local,
+    options: KeyOptions,
+) -> bool {
+    let mode = if options.include_prototypes {
+        napi::KeyCollectionMode::IncludePrototypes
+    } else {
+        napi::KeyCollectionMode::OwnOnly
+    };
+
+    let mut filter = napi::KeyFilter::ALL_PROPERTIES;
+
+    if options.writable {
+        filter |= napi::KeyFilter::WRITABLE;
+    }
+
+    if options.enumerable {
+        filter |= napi::KeyFilter::ENUMERABLE;
+    }
+
+    if options.configurable {
+        filter |= napi::KeyFilter::CONFIGURABLE;
+    }
+
+    if options.skip_strings {
+        filter |= napi::KeyFilter::SKIP_STRINGS;
+    }
+
+    if options.skip_symbols {
+        filter |= napi::KeyFilter::SKIP_SYMBOLS;
+    }
+
+    let conversion = if options.numbers_to_strings {
+        napi::KeyConversion::NumbersToStrings
+    } else {
+        napi::KeyConversion::KeepNumbers
+    };
+
     let mut property_names = MaybeUninit::uninit();
 
     if napi::get_all_property_names(
         env,
         object,
-        napi::KeyCollectionMode::OwnOnly,
-        napi::KeyFilter::ALL_PROPERTIES | napi::KeyFilter::SKIP_SYMBOLS,
-        napi::KeyConversion::NumbersToStrings,
+        mode,
+        filter,
+        conversion,
         property_names.as_mut_ptr(),
     ) != napi::Status::Ok
     {
@@ -131,3 +205,162 @@ pub unsafe fn set(out: &mut bool, env: Env, object: Local, key: Local, val: Loca
 
     *out
 }
+
+/// Mutates `out` to `true` if `object` or its prototype chain has a property named by
+/// the `key` value. Returns `false` if the check threw an exception.
+pub unsafe fn has(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    let status = napi::has_property(env, object, key, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Mutates `out` to `true` if `object` has an own property named by the `key` value.
+/// Returns `false` if the check threw an exception or `key` is not a string or symbol.
+pub unsafe fn has_own(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    let status = napi::has_own_property(env, object, key, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Deletes the property of `object` named by the `key` value. Mutates `out` to `false`
+/// if the property exists and could not be deleted. Returns `false` if the deletion
+/// threw an exception.
+pub unsafe fn delete(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    let status = napi::delete_property(env, object, key, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Mutates `out` to refer to the prototype of `object`, which may be `null`.
+pub unsafe fn get_prototype(out: &mut Local, env: Env, object: Local) -> bool {
+    let status = napi::get_prototype(env, object, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+#[cfg(feature = "napi-8")]
+/// Freezes `object`, as with `Object.freeze`. Returns `false` if an exception was thrown.
+pub unsafe fn freeze(env: Env, object: Local) -> bool {
+    napi::object_freeze(env, object) == napi::Status::Ok
+}
+
+#[cfg(feature = "napi-8")]
+/// Seals `object`, as with `Object.seal`. Returns `false` if an exception was thrown.
+pub unsafe fn seal(env: Env, object: Local) -> bool {
+    napi::object_seal(env, object) == napi::Status::Ok
+}
+
+/// Defines the properties described by `properties` on `object`. Returns `false` if
+/// the properties couldn't be defined, e.g., because a non-configurable property
+/// would be redefined.
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `properties` contain valid `napi_value` names and callbacks expecting their `data`
+pub unsafe fn define_properties(env: Env, object: Local, properties: &[Descriptor]) -> bool {
+    let properties = properties
+        .iter()
+        .map(|property| property.to_napi())
+        .collect::<Vec<_>>();
+
+    let status = napi::define_properties(env, object, properties.len(), properties.as_ptr());
+
+    status == napi::Status::Ok
+}
+
+/// Transfers ownership of `data` to `object`. `finalize` is called with `data`
+/// immediately before the object is garbage collected. Returns `false`, without
+/// calling `finalize`, if the finalizer couldn't be added.
+#[cfg(feature = "napi-5")]
+pub unsafe fn add_finalizer(
+    env: Env,
+    object: Local,
+    data: *mut c_void,
+    finalize: fn(*mut c_void),
+) -> bool {
+    let status = napi::add_finalizer(
+        env,
+        object,
+        data,
+        Some(call_finalize),
+        // Casting to `*const ()` is required to ensure the correct layout
+        // https://rust-lang.github.io/unsafe-code-guidelines/layout/function-pointers.html
+        finalize as *const () as *mut _,
+        std::ptr::null_mut(),
+    );
+
+    status == napi::Status::Ok
+}
+
+#[cfg(feature = "napi-5")]
+unsafe extern "C" fn call_finalize(_env: Env, data: *mut c_void, hint: *mut c_void) {
+    let finalize: fn(*mut c_void) = std::mem::transmute(hint as *const ());
+
+    finalize(data);
+}
+
+/// Associates a Rust value with a JavaScript object. `finalizer` is called with the
+/// value immediately before the object is garbage collected. Returns `false` if the
+/// value couldn't be wrapped, e.g., because the object is already wrapped.
+///
+/// # Safety
+/// `env` must point to a valid `napi_env` for this thread
+pub unsafe fn wrap<T: Send + 'static>(
+    env: Env,
+    object: Local,
+    v: T,
+    finalizer: fn(Env, T),
+) -> bool {
+    let v = Box::into_raw(Box::new(v));
+    let status = napi::wrap(
+        env,
+        object,
+        v as *mut _,
+        Some(crate::napi::external::finalize_external::<T>),
+        // Casting to `*const ()` is required to ensure the correct layout
+        // https://rust-lang.github.io/unsafe-code-guidelines/layout/function-pointers.html
+        finalizer as *const () as *mut _,
+        std::ptr::null_mut(),
+    );
+
+    if status != napi::Status::Ok {
+        // The value was not wrapped and is still owned by Rust
+        drop(Box::from_raw(v));
+        return false;
+    }
+
+    true
+}
+
+/// Returns a pointer to the Rust value wrapped by `object`, or `None` if `object`
+/// is not wrapped.
+///
+/// # Safety
+/// `env` must point to a valid `napi_env` for this thread. The pointer may have been
+/// wrapped by another native module, even another neon module; it must only be
+/// dereferenced if it is known to have been returned by `wrap` with the same type `T`.
+pub unsafe fn unwrap<T: Send + 'static>(env: Env, object: Local) -> Option<*const T> {
+    let mut result = MaybeUninit::uninit();
+
+    if napi::unwrap(env, object, result.as_mut_ptr()) != napi::Status::Ok {
+        return None;
+    }
+
+    Some(result.assume_init() as *const T)
+}
+
+/// Removes the Rust value wrapped by `object` and returns it, or `None` if `object`
+/// is not wrapped. The finalizer passed to `wrap` is not called.
+///
+/// # Safety
+/// `remove_wrap` must only be called with objects wrapped by `wrap` with the same
+/// type `T` in this module, and no references returned by `unwrap` may be alive.
+pub unsafe fn remove_wrap<T: Send + 'static>(env: Env, object: Local) -> Option<T> {
+    let mut result = MaybeUninit::uninit();
+
+    if napi::remove_wrap(env, object, result.as_mut_ptr()) != napi::Status::Ok {
+        return None;
+    }
+
+    Some(*Box::from_raw(result.assume_init() as *mut T))
+}
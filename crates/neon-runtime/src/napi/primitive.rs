@@ -43,3 +43,73 @@ pub unsafe fn number_value(env: Env, p: Local) -> f64 {
     );
     value
 }
+
+/// Mutates the `out` argument provided to refer to a newly created `Local` containing a
+/// JavaScript number with the value of an `i32`.
+pub unsafe fn number_i32(out: &mut Local, env: Env, v: i32) {
+    assert_eq!(
+        napi::create_int32(env, v, out as *mut Local),
+        napi::Status::Ok
+    );
+}
+
+/// Mutates the `out` argument provided to refer to a newly created `Local` containing a
+/// JavaScript number with the value of a `u32`.
+pub unsafe fn number_u32(out: &mut Local, env: Env, v: u32) {
+    assert_eq!(
+        napi::create_uint32(env, v, out as *mut Local),
+        napi::Status::Ok
+    );
+}
+
+/// Mutates the `out` argument provided to refer to a newly created `Local` containing a
+/// JavaScript number with the value of an `i64`. Precision is lost outside of the range
+/// of safe integers.
+pub unsafe fn number_i64(out: &mut Local, env: Env, v: i64) {
+    assert_eq!(
+        napi::create_int64(env, v, out as *mut Local),
+        napi::Status::Ok
+    );
+}
+
+/// Gets the value of a `Local` containing a JavaScript number converted to an `i32`, as
+/// with the `ToInt32` abstract operation. Panics if the given `Local` is not a number.
+pub unsafe fn number_value_i32(env: Env, p: Local) -> i32 {
+    let mut value = 0;
+    assert_eq!(
+        napi::get_value_int32(env, p, &mut value as *mut i32),
+        napi::Status::Ok
+    );
+    value
+}
+
+/// Gets the value of a `Local` containing a JavaScript number converted to a `u32`, as
+/// with the `ToUint32` abstract operation. Panics if the given `Local` is not a number.
+pub unsafe fn number_value_u32(env: Env, p: Local) -> u32 {
+    let mut value = 0;
+    assert_eq!(
+        napi::get_value_uint32(env, p, &mut value as *mut u32),
+        napi::Status::Ok
+    );
+    value
+}
+
+/// Gets the value of a `Local` containing a JavaScript number truncated to an `i64`.
+/// Non-finite numbers are converted to `0`. Panics if the given `Local` is not a number.
+pub unsafe fn number_value_i64(env: Env, p: Local) -> i64 {
+    let mut value = 0;
+    assert_eq!(
+        napi::get_value_int64(env, p, &mut value as *mut i64),
+        napi::Status::Ok
+    );
+    value
+}
+
+/// Mutates the `out` argument provided to refer to a newly created `Local` containing a
+/// unique JavaScript symbol. The `description` must be a JavaScript string or `NULL`.
+pub unsafe fn symbol(out: &mut Local, env: Env, description: Local) {
+    assert_eq!(
+        napi::create_symbol(env, description, out as *mut Local),
+        napi::Status::Ok
+    );
+}
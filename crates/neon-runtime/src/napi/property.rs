@@ -0,0 +1,91 @@
+//! Descriptors for defining JavaScript object properties.
+
+use std::os::raw::c_void;
+use std::ptr::null_mut;
+
+use crate::napi::bindings as napi;
+use crate::raw::Local;
+
+/// Attributes of a property defined with a [`Descriptor`](Descriptor)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Attributes {
+    pub writable: bool,
+    pub enumerable: bool,
+    pub configurable: bool,
+    /// Only applicable to class properties; defines the property on the constructor
+    /// instead of the prototype.
+    pub is_static: bool,
+}
+
+/// Describes a property to define on an object or a class.
+///
+/// A property is either a data property with a `value` or a `method`, or an accessor
+/// property with a `getter` and/or `setter`. Callbacks are `static_callback` pointers
+/// as found in a [`CCallback`](crate::call::CCallback) and are all passed `data`.
+/// A null callback or value is omitted.
+#[derive(Clone, Copy)]
+pub struct Descriptor {
+    /// Property key; a string or symbol
+    pub name: Local,
+    pub method: *mut c_void,
+    pub getter: *mut c_void,
+    pub setter: *mut c_void,
+    pub value: Local,
+    pub attributes: Attributes,
+    pub data: *mut c_void,
+}
+
+impl Default for Descriptor {
+    fn default() -> Self {
+        Descriptor {
+            name: null_mut(),
+            method: null_mut(),
+            getter: null_mut(),
+            setter: null_mut(),
+            value: null_mut(),
+            attributes: Attributes::default(),
+            data: null_mut(),
+        }
+    }
+}
+
+impl Descriptor {
+    pub(crate) unsafe fn to_napi(self) -> napi::PropertyDescriptor {
+        let Attributes {
+            writable,
+            enumerable,
+            configurable,
+            is_static,
+        } = self.attributes;
+
+        let mut attributes = napi::PropertyAttributes::DEFAULT;
+
+        if writable {
+            attributes |= napi::PropertyAttributes::WRITABLE;
+        }
+
+        if enumerable {
+            attributes |= napi::PropertyAttributes::ENUMERABLE;
+        }
+
+        if configurable {
+            attributes |= napi::PropertyAttributes::CONFIGURABLE;
+        }
+
+        if is_static {
+            attributes |= napi::PropertyAttributes::STATIC;
+        }
+
+        // A null `*mut c_void` is transmuted to `None`
+        napi::PropertyDescriptor {
+            utf8name: std::ptr::null(),
+            name: self.name,
+            method: std::mem::transmute::<*mut c_void, napi::Callback>(self.method),
+            getter: std::mem::transmute::<*mut c_void, napi::Callback>(self.getter),
+            setter: std::mem::transmute::<*mut c_void, napi::Callback>(self.setter),
+            value: self.value,
+            attributes,
+            data: self.data,
+        }
+    }
+}
@@ -8,6 +8,12 @@ pub type FunctionCallbackInfo = napi::CallbackInfo;
 
 pub type Env = napi::Env;
 
+pub type Deferred = napi::Deferred;
+
+pub type AsyncWork = napi::AsyncWork;
+
+pub type TypedArrayType = napi::TypedArrayType;
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct HandleScope {
@@ -29,6 +29,64 @@ pub unsafe fn data(env: Env, out: *mut u8, len: isize, value: Local) -> isize {
     read.assume_init() as isize
 }
 
+/// Mutates `out` to refer to a new string with the UTF-16 code units of `data`, which
+/// may include unpaired surrogates. Returns `false` if the string is too long.
+pub unsafe fn new_utf16(out: &mut Local, env: Env, data: &[u16]) -> bool {
+    let status = napi::create_string_utf16(env, data.as_ptr(), data.len(), out);
+
+    status == napi::Status::Ok
+}
+
+/// Mutates `out` to refer to a new string with the Latin-1 characters of `data`.
+/// Returns `false` if the string is too long.
+pub unsafe fn new_latin1(out: &mut Local, env: Env, data: &[u8]) -> bool {
+    let status = napi::create_string_latin1(env, data.as_ptr().cast(), data.len(), out);
+
+    status == napi::Status::Ok
+}
+
+/// Returns the length of the string in UTF-16 code units, which is also its length
+/// in Latin-1 characters.
+pub unsafe fn utf16_len(env: Env, value: Local) -> usize {
+    let mut len = MaybeUninit::uninit();
+    let status = napi::get_value_string_utf16(env, value, ptr::null_mut(), 0, len.as_mut_ptr());
+
+    assert_eq!(status, napi::Status::Ok);
+
+    len.assume_init()
+}
+
+/// Copies the UTF-16 code units of the string into `buf`, followed by a null terminator,
+/// and returns the number of code units copied, excluding the terminator. At most
+/// `buf.len() - 1` code units are copied.
+pub unsafe fn data_utf16(env: Env, buf: &mut [u16], value: Local) -> usize {
+    let mut read = MaybeUninit::uninit();
+    let status =
+        napi::get_value_string_utf16(env, value, buf.as_mut_ptr(), buf.len(), read.as_mut_ptr());
+
+    assert_eq!(status, napi::Status::Ok);
+
+    read.assume_init()
+}
+
+/// Copies the string into `buf` as Latin-1, followed by a null terminator, and returns
+/// the number of characters copied, excluding the terminator. At most `buf.len() - 1`
+/// characters are copied; characters outside of Latin-1 are truncated to their low byte.
+pub unsafe fn data_latin1(env: Env, buf: &mut [u8], value: Local) -> usize {
+    let mut read = MaybeUninit::uninit();
+    let status = napi::get_value_string_latin1(
+        env,
+        value,
+        buf.as_mut_ptr().cast(),
+        buf.len(),
+        read.as_mut_ptr(),
+    );
+
+    assert_eq!(status, napi::Status::Ok);
+
+    read.assume_init()
+}
+
 pub unsafe fn run_script(out: &mut Local, env: Env, value: Local) -> bool {
     let status = napi::run_script(env, value, out as *mut _);
 
@@ -2,11 +2,23 @@
 
 use std::ffi::c_void;
 use std::mem::MaybeUninit;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, ThreadId};
 
 use crate::napi::bindings as napi;
 use crate::raw::{Env, Local};
 
+/// Behavior of scheduling a threadsafe function when its queue is full
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallMode {
+    /// Block until there is space in the queue. Since the queue is drained on the
+    /// JavaScript thread, calls on the thread that created the function fail with
+    /// `QueueFull` instead of blocking forever.
+    Blocking,
+    /// Fail with `QueueFull`
+    NonBlocking,
+}
+
 unsafe fn string(env: Env, s: impl AsRef<str>) -> Local {
     let s = s.as_ref();
     let mut result = MaybeUninit::uninit();
@@ -35,10 +47,20 @@ unsafe impl Sync for Tsfn {}
 /// function for scheduling tasks to execute on a JavaScript thread.
 pub struct ThreadsafeFunction<T> {
     tsfn: Tsfn,
-    is_finalized: Arc<Mutex<bool>>,
+    shared: Arc<Shared>,
+    thread: ThreadId,
     callback: fn(Option<Env>, T),
 }
 
+/// State shared with the N-API callbacks of a threadsafe function
+#[derive(Debug)]
+struct Shared {
+    is_finalized: Mutex<bool>,
+    /// Notified when a call is dequeued or the threadsafe function is finalized.
+    /// Only a bounded queue can be full; `None` if the queue is unbounded.
+    dequeued: Option<Condvar>,
+}
+
 #[derive(Debug)]
 struct Callback<T> {
     callback: fn(Option<Env>, T),
@@ -57,6 +79,11 @@ impl<T> CallError<T> {
         self.kind
     }
 
+    /// Whether the call failed because the queue is full
+    pub fn is_queue_full(&self) -> bool {
+        self.kind == napi::Status::QueueFull
+    }
+
     /// Returns the data that was sent when scheduling to allow re-scheduling
     pub fn into_inner(self) -> T {
         self.data
@@ -78,7 +105,24 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
         callback: fn(Option<Env>, T),
     ) -> Self {
         let mut result = MaybeUninit::uninit();
-        let is_finalized = Arc::new(Mutex::new(false));
+        let is_bounded = max_queue_size > 0;
+        let shared = Arc::new(Shared {
+            is_finalized: Mutex::new(false),
+            dequeued: if is_bounded {
+                Some(Condvar::new())
+            } else {
+                None
+            },
+        });
+        // Owned by `finalize`. If the queue is bounded, the pointer is also passed
+        // as the `context` of `callback`, which only uses it while the environment
+        // is running.
+        let data = Arc::into_raw(shared.clone()) as *mut c_void;
+        let context = if is_bounded {
+            data
+        } else {
+            std::ptr::null_mut()
+        };
 
         assert_eq!(
             napi::create_threadsafe_function(
@@ -90,9 +134,9 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
                 // Always set the reference count to 1. Prefer using
                 // Rust `Arc` to maintain the struct.
                 1,
-                Arc::into_raw(is_finalized.clone()) as *mut _,
+                data,
                 Some(Self::finalize),
-                std::ptr::null_mut(),
+                context,
                 Some(Self::callback),
                 result.as_mut_ptr(),
             ),
@@ -101,18 +145,17 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
 
         Self {
             tsfn: Tsfn(result.assume_init()),
-            is_finalized: is_finalized,
+            shared,
+            thread: thread::current().id(),
             callback,
         }
     }
 
     /// Schedule a threadsafe function to be executed with some data
-    pub fn call(
-        &self,
-        data: T,
-        is_blocking: Option<napi::ThreadsafeFunctionCallMode>,
-    ) -> Result<(), CallError<T>> {
-        let is_blocking = is_blocking.unwrap_or(napi::ThreadsafeFunctionCallMode::Blocking);
+    pub fn call(&self, data: T, is_blocking: Option<CallMode>) -> Result<(), CallError<T>> {
+        // Blocking on the JavaScript thread would wait for the queue to drain forever
+        let is_blocking = is_blocking.unwrap_or(CallMode::Blocking) == CallMode::Blocking
+            && thread::current().id() != self.thread;
 
         let callback = Box::into_raw(Box::new(Callback {
             callback: self.callback,
@@ -120,17 +163,31 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
         }));
 
         // Hold the lock before entering `call_threadsafe_function` so that
-        // `finalize_cb` would never complete.
-        let mut is_finalized = self.is_finalized.lock().unwrap();
+        // `finalize_cb` would never complete. The call itself never blocks; a
+        // blocking call waits for a queued call to be dequeued, releasing the
+        // lock, and tries again. Otherwise, the JavaScript thread could wait for
+        // the lock while the queue can only be drained by the JavaScript thread.
+        let mut is_finalized = self.shared.is_finalized.lock().unwrap();
 
-        let status = {
+        let status = loop {
             if *is_finalized {
-                napi::Status::Closing
-            } else {
-                unsafe {
-                    napi::call_threadsafe_function(self.tsfn.0, callback as *mut _, is_blocking)
-                }
+                break napi::Status::Closing;
             }
+
+            let status = unsafe {
+                napi::call_threadsafe_function(
+                    self.tsfn.0,
+                    callback as *mut _,
+                    napi::ThreadsafeFunctionCallMode::NonBlocking,
+                )
+            };
+
+            let dequeued = match &self.shared.dequeued {
+                Some(dequeued) if status == napi::Status::QueueFull && is_blocking => dequeued,
+                _ => break status,
+            };
+
+            is_finalized = dequeued.wait(is_finalized).unwrap();
         };
 
         if status == napi::Status::Ok {
@@ -172,20 +229,37 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
     // Provides a C ABI wrapper for a napi callback notifying us about tsfn
     // being finalized.
     unsafe extern "C" fn finalize(_env: Env, data: *mut c_void, _hint: *mut c_void) {
-        let is_finalized = Arc::from_raw(data as *mut Mutex<bool>);
+        let shared = Arc::from_raw(data as *const Shared);
+
+        *shared.is_finalized.lock().unwrap() = true;
 
-        *is_finalized.lock().unwrap() = true;
+        if let Some(dequeued) = &shared.dequeued {
+            dequeued.notify_all();
+        }
     }
 
     // Provides a C ABI wrapper for invoking the user supplied function pointer
     unsafe extern "C" fn callback(
         env: Env,
         _js_callback: napi::Value,
-        _context: *mut c_void,
+        context: *mut c_void,
         data: *mut c_void,
     ) {
         let Callback { callback, data } = *Box::from_raw(data as *mut Callback<T>);
 
+        // Wake blocked calls waiting for space in a bounded queue. Acquiring the lock
+        // ensures a call that found the queue full is already waiting. The remaining
+        // queue is emptied after `finalize`, with a null `env`, when `context` is no
+        // longer valid.
+        if !env.is_null() && !context.is_null() {
+            let shared = &*(context as *const Shared);
+
+            if let Some(dequeued) = &shared.dequeued {
+                drop(shared.is_finalized.lock().unwrap());
+                dequeued.notify_all();
+            }
+        }
+
         // Event loop has terminated
         let env = if env.is_null() { None } else { Some(env) };
 
@@ -195,7 +269,7 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
 
 impl<T> Drop for ThreadsafeFunction<T> {
     fn drop(&mut self) {
-        let is_finalized = self.is_finalized.lock().unwrap();
+        let is_finalized = self.shared.is_finalized.lock().unwrap();
 
         // tsfn was already finalized by `Environment::CleanupHandles()` in
         // Node.js
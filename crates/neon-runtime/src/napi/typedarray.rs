@@ -0,0 +1,80 @@
+//! Facilities for working with TypedArray views over an ArrayBuffer.
+//!
+//! See: [TypedArray in N-API](https://nodejs.org/api/n-api.html#n_api_napi_create_typedarray)
+
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+use std::ptr::null_mut;
+
+use crate::napi::bindings as napi;
+use crate::raw::{Env, Local, TypedArrayType};
+
+/// The layout of a TypedArray, as reported by `napi_get_typedarray_info`
+#[derive(Debug, Clone, Copy)]
+pub struct TypedArrayInfo {
+    /// The element type of the array
+    pub kind: TypedArrayType,
+    /// Pointer to the first element, already adjusted by `offset`
+    pub data: *mut c_void,
+    /// Number of elements in the array
+    pub length: usize,
+    /// The underlying ArrayBuffer
+    pub buf: Local,
+    /// Offset of the first element in the ArrayBuffer, in bytes
+    pub offset: usize,
+}
+
+/// Mutates the `out` argument to refer to a new TypedArray of `length` elements
+/// over `buf`, starting at `offset` bytes. Returns `false` if the array couldn't be
+/// created, e.g., because the range is out of bounds or misaligned; a `RangeError`
+/// will be pending.
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `buf` is an ArrayBuffer
+pub unsafe fn new(
+    out: &mut Local,
+    env: Env,
+    kind: TypedArrayType,
+    buf: Local,
+    offset: usize,
+    length: usize,
+) -> bool {
+    let status = napi::create_typedarray(env, kind, length, buf, offset, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Get the layout of a TypedArray
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `value` is a TypedArray
+pub unsafe fn info(env: Env, value: Local) -> TypedArrayInfo {
+    let mut kind = MaybeUninit::uninit();
+    let mut data = null_mut();
+    let mut length = 0;
+    let mut buf = MaybeUninit::uninit();
+    let mut offset = 0;
+
+    assert_eq!(
+        napi::get_typedarray_info(
+            env,
+            value,
+            kind.as_mut_ptr(),
+            &mut length,
+            &mut data,
+            buf.as_mut_ptr(),
+            &mut offset,
+        ),
+        napi::Status::Ok,
+    );
+
+    TypedArrayInfo {
+        kind: kind.assume_init(),
+        data,
+        length,
+        buf: buf.assume_init(),
+        offset,
+    }
+}
@@ -9,13 +9,11 @@ use neon_runtime::raw;
 use neon_runtime::scope::Root;
 #[cfg(feature = "legacy-runtime")]
 use neon_runtime::try_catch::TryCatchControl;
-#[cfg(feature = "legacy-runtime")]
 use std::any::Any;
 use std::cell::{Cell, RefCell};
 use std::mem::MaybeUninit;
 #[cfg(feature = "legacy-runtime")]
 use std::os::raw::c_void;
-#[cfg(feature = "legacy-runtime")]
 use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
 
 #[cfg(feature = "legacy-runtime")]
@@ -176,17 +174,65 @@ pub trait ContextInternal<'a>: Sized {
     where
         F: FnOnce(&mut Self) -> NeonResult<T>,
     {
-        let result = f(self);
+        match self.try_catch_unwind(f) {
+            Ok(result) => result,
+            Err(panic) => resume_unwind(panic),
+        }
+    }
+
+    /// Calls `f` and catches both JavaScript exceptions and Rust panics. If `f` panics,
+    /// any exception it left pending is cleared before returning the panic, so the
+    /// context can continue to be used.
+    #[cfg(feature = "napi-1")]
+    #[allow(clippy::type_complexity)]
+    fn try_catch_unwind<T, F>(
+        &mut self,
+        f: F,
+    ) -> Result<Result<T, Handle<'a, JsValue>>, Box<dyn Any + Send>>
+    where
+        F: FnOnce(&mut Self) -> NeonResult<T>,
+    {
+        let env = self.env().to_raw();
+
+        // As in `try_catch_glue`, the context holds no Rust data that a panic could
+        // leave inconsistent, so it's safe to keep using it after unwinding
+        let result = match catch_unwind(AssertUnwindSafe(|| f(self))) {
+            Ok(result) => result,
+            Err(panic) => {
+                unsafe { neon_runtime::error::clear_exception(env) };
+                return Err(panic);
+            }
+        };
+
         let mut local: MaybeUninit<raw::Local> = MaybeUninit::zeroed();
+
         unsafe {
-            if neon_runtime::error::catch_error(self.env().to_raw(), local.as_mut_ptr()) {
-                Err(JsValue::new_internal(local.assume_init()))
-            } else if let Ok(result) = result {
-                Ok(result)
-            } else {
-                panic!("try_catch: unexpected Err(Throw) when VM is not in a throwing state");
+            if neon_runtime::error::catch_error(env, local.as_mut_ptr()) {
+                return Ok(Err(JsValue::new_internal(local.assume_init())));
             }
         }
+
+        match result {
+            Ok(result) => Ok(Ok(result)),
+            // `Err(Throw)` was returned without throwing, e.g., by propagating the
+            // result of an inner `try_catch` closure. Report it as an exception
+            // instead of leaving the caller without a value.
+            Err(_) => unsafe {
+                let msg = "try_catch: closure returned Err(Throw) without a pending exception";
+                let mut msg_local: raw::Local = std::ptr::null_mut();
+                let mut err: raw::Local = std::ptr::null_mut();
+
+                assert!(neon_runtime::string::new(
+                    &mut msg_local,
+                    env,
+                    msg.as_ptr(),
+                    msg.len() as i32
+                ));
+                neon_runtime::error::new_error(env, &mut err, msg_local);
+
+                Ok(Err(JsValue::new_internal(err)))
+            },
+        }
     }
 }
 
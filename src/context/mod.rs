@@ -160,12 +160,16 @@ use crate::lifecycle::InstanceData;
 use crate::object::class::Class;
 use crate::object::{Object, This};
 use crate::result::{JsResult, NeonResult, Throw};
+#[cfg(feature = "napi-1")]
+use crate::task::TaskBuilder;
 use crate::types::binary::{JsArrayBuffer, JsBuffer};
 #[cfg(feature = "napi-1")]
 use crate::types::boxed::{Finalize, JsBox};
 #[cfg(feature = "napi-5")]
 use crate::types::date::{DateError, JsDate};
 use crate::types::error::JsError;
+#[cfg(feature = "napi-1")]
+use crate::types::promise::{Deferred, JsPromise};
 use crate::types::{
     JsArray, JsBoolean, JsFunction, JsNull, JsNumber, JsObject, JsString, JsUndefined, JsValue,
     StringResult, Value,
@@ -177,6 +181,8 @@ use smallvec::SmallVec;
 use std;
 use std::cell::RefCell;
 use std::convert::Into;
+#[cfg(all(feature = "napi-6", feature = "tokio-rt"))]
+use std::future::Future;
 use std::marker::PhantomData;
 use std::os::raw::c_void;
 use std::panic::UnwindSafe;
@@ -408,7 +414,37 @@ pub trait Context<'a>: ContextInternal<'a> {
         result
     }
 
-    #[cfg(feature = "try-catch-api")]
+    /// Executes a computation and catches any JavaScript exception it throws,
+    /// returning the thrown value as an `Err` and restoring the context to a
+    /// non-throwing state.
+    ///
+    /// Calls may be nested; each call only catches exceptions thrown by its own
+    /// closure. With the N-API backends, if the closure panics, any exception it
+    /// left pending is cleared before the panic resumes unwinding, and a closure
+    /// that returns `Err(Throw)` without throwing results in an `Error` instead of
+    /// a panic.
+    ///
+    /// The N-API backends always provide this method; the legacy backend requires
+    /// the `try-catch-api` feature.
+    ///
+    /// ```
+    /// # #[cfg(feature = "napi-1")] {
+    /// # use neon::prelude::*;
+    /// fn call_plugin(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let plugin = cx.argument::<JsFunction>(0)?;
+    ///     let result = cx.try_catch(|cx| {
+    ///         let null = cx.null();
+    ///         plugin.call(cx, null, Vec::<Handle<JsValue>>::new())
+    ///     });
+    ///
+    ///     match result {
+    ///         Ok(value) => Ok(value),
+    ///         Err(_) => Ok(cx.string("plugin failed").upcast()),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[cfg(any(feature = "try-catch-api", feature = "napi-1"))]
     fn try_catch<T, F>(&mut self, f: F) -> Result<T, Handle<'a, JsValue>>
     where
         F: FnOnce(&mut Self) -> NeonResult<T>,
@@ -416,6 +452,34 @@ pub trait Context<'a>: ContextInternal<'a> {
         self.try_catch_internal(f)
     }
 
+    /// Executes a computation as with [`try_catch`](Context::try_catch), then
+    /// executes `finally`, whether the computation returned, threw an exception or
+    /// panicked.
+    ///
+    /// As with a JavaScript `finally` block, an exception thrown by `finally` replaces
+    /// the result of the computation. If the computation panicked, the panic resumes
+    /// after `finally` completes.
+    #[cfg(feature = "napi-1")]
+    fn try_catch_finally<T, F, G>(&mut self, f: F, finally: G) -> Result<T, Handle<'a, JsValue>>
+    where
+        F: FnOnce(&mut Self) -> NeonResult<T>,
+        G: FnOnce(&mut Self) -> NeonResult<()>,
+    {
+        let result = self.try_catch_unwind(f);
+        let finally = self.try_catch_unwind(finally);
+
+        let result = match result {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        };
+
+        match finally {
+            Ok(Ok(())) => result,
+            Ok(Err(err)) => Err(err),
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+
     /// Convenience method for creating a `JsBoolean` value.
     fn boolean(&mut self, b: bool) -> Handle<'a, JsBoolean> {
         JsBoolean::new(self, b)
@@ -551,6 +615,55 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsBox::new(self, v)
     }
 
+    #[cfg(feature = "napi-1")]
+    /// Creates a new `JsPromise` and a [`Deferred`](crate::types::Deferred) handle
+    /// for settling it.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use neon::prelude::*;
+    /// fn resolve_later(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let value = cx.argument::<JsValue>(0)?;
+    ///     let (deferred, promise) = cx.promise();
+    ///
+    ///     deferred.resolve(&mut cx, value);
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    fn promise(&mut self) -> (Deferred, Handle<'a, JsPromise>) {
+        JsPromise::new(self)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a [`TaskBuilder`](crate::task::TaskBuilder) for executing `execute` on
+    /// the libuv thread pool, e.g., to return a promise of its result.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use neon::prelude::*;
+    /// # fn fibonacci(_: u64) -> u64 { todo!() }
+    /// fn fibonacci_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+    ///     let promise = cx
+    ///         .task(move || fibonacci(n))
+    ///         .promise(|mut cx, n| Ok(cx.number(n as f64)));
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    fn task<'cx, O, E>(&'cx mut self, execute: E) -> TaskBuilder<'cx, Self, E>
+    where
+        'a: 'cx,
+        Self: Sized,
+        O: Send + 'static,
+        E: FnOnce() -> O + Send + 'static,
+    {
+        TaskBuilder::new(self, execute)
+    }
+
     #[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
     /// Returns an unbounded channel for scheduling events to be executed on the JavaScript thread.
     ///
@@ -566,6 +679,38 @@ pub trait Context<'a>: ContextInternal<'a> {
         channel
     }
 
+    #[cfg(all(feature = "napi-6", feature = "tokio-rt"))]
+    /// Spawns `future` on the Tokio runtime of this module instance and returns a
+    /// promise that is settled on the JavaScript thread with the result of `complete`.
+    ///
+    /// The promise is rejected with the exception if `complete` throws, or with an
+    /// `Error` if either `future` or `complete` panics. See [`executor`](crate::executor)
+    /// for details on the runtime.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use neon::prelude::*;
+    /// # async fn fetch_len(_: String) -> usize { todo!() }
+    /// fn fetch_len_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let url = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     cx.spawn_promise(async move { fetch_len(url).await }, |mut cx, len| {
+    ///         Ok(cx.number(len as f64))
+    ///     })
+    /// }
+    /// ```
+    fn spawn_promise<Fut, V, F>(&mut self, future: Fut, complete: F) -> JsResult<'a, JsPromise>
+    where
+        Self: Sized,
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+        V: Value,
+        F: FnOnce(TaskContext, Fut::Output) -> JsResult<V> + Send + 'static,
+    {
+        crate::executor::spawn_promise(self, future, complete)
+    }
+
     #[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
     #[deprecated(since = "0.9.0", note = "Please use the channel() method instead")]
     #[doc(hidden)]
@@ -815,7 +960,7 @@ impl<'a> TaskContext<'a> {
         Scope::with(env, |scope| f(TaskContext { scope }))
     }
 
-    #[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+    #[cfg(feature = "napi-1")]
     pub(crate) fn with_context<T, F: for<'b> FnOnce(TaskContext<'b>) -> T>(env: Env, f: F) -> T {
         Scope::with(env, |scope| f(TaskContext { scope }))
     }
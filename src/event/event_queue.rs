@@ -1,13 +1,21 @@
+use std::any::Any;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
-use neon_runtime::raw::Env;
-use neon_runtime::tsfn::ThreadsafeFunction;
+use neon_runtime::raw::{self, Env};
+use neon_runtime::tsfn::{CallMode, ThreadsafeFunction};
 
+use super::join_handle::{self, JoinError, JoinErrorKind, JoinHandle};
+use crate::context::internal;
 use crate::context::{Context, TaskContext};
+use crate::handle::Handle;
 use crate::result::NeonResult;
+use crate::types::JsValue;
 
-type Callback = Box<dyn FnOnce(Env) + Send + 'static>;
+type Callback = Box<dyn Task>;
+
+type ErrorHandler =
+    dyn for<'a> Fn(TaskContext<'a>, Handle<'a, JsValue>) -> NeonResult<()> + Send + Sync;
 
 /// Channel for scheduling Rust closures to execute on the JavaScript main thread.
 ///
@@ -54,10 +62,20 @@ type Callback = Box<dyn FnOnce(Env) + Send + 'static>;
 ///     Ok(cx.undefined())
 /// }
 /// ```
+///
+/// # Exceptions
+///
+/// If a closure throws, i.e., it returns `Err(Throw)`, the exception is passed to the
+/// error handler registered with [`Channel::on_error`]. Without a handler, or if the
+/// handler throws, the exception is reported to Node as an uncaught exception and
+/// emits an [`uncaughtException`][uncaught] event on `process`.
+///
+/// [uncaught]: https://nodejs.org/api/process.html#process_event_uncaughtexception
 
 pub struct Channel {
     state: Arc<ChannelState>,
     has_ref: bool,
+    error_handler: Option<Arc<ErrorHandler>>,
 }
 
 impl std::fmt::Debug for Channel {
@@ -71,11 +89,94 @@ impl Channel {
     /// main thread
     pub fn new<'a, C: Context<'a>>(cx: &mut C) -> Self {
         Self {
-            state: Arc::new(ChannelState::new(cx)),
+            state: Arc::new(ChannelState::new(cx, 0)),
             has_ref: true,
+            error_handler: None,
         }
     }
 
+    /// Creates a channel for scheduling closures on the JavaScript main thread that
+    /// queues at most `capacity` closures. When the queue is full, [`send`](Channel::send)
+    /// blocks until a closure has executed and [`try_send`](Channel::try_send) fails.
+    ///
+    /// Calling `send` or `send_joinable` on a full queue from the JavaScript thread
+    /// itself panics, since the queue is only drained by that thread.
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn stream_events(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    ///     let callback = std::sync::Arc::new(callback);
+    ///     let channel = Channel::bounded(&mut cx, 64);
+    ///
+    ///     std::thread::spawn(move || {
+    ///         for i in 0..10_000 {
+    ///             let callback = callback.clone();
+    ///
+    ///             // Blocks while 64 events are waiting to be handled
+    ///             channel.send(move |mut cx| {
+    ///                 let callback = callback.to_inner(&mut cx);
+    ///                 let this = cx.undefined();
+    ///                 let args = vec![cx.number(i)];
+    ///
+    ///                 callback.call(&mut cx, this, args)?;
+    ///
+    ///                 Ok(())
+    ///             });
+    ///         }
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn bounded<'a, C: Context<'a>>(cx: &mut C, capacity: usize) -> Self {
+        assert!(capacity > 0, "Channel capacity must be greater than zero");
+
+        Self {
+            state: Arc::new(ChannelState::new(cx, capacity)),
+            has_ref: true,
+            error_handler: None,
+        }
+    }
+
+    /// Handles exceptions thrown by closures sent on this `Channel`, instead of
+    /// reporting them as uncaught exceptions. The handler is called on the JavaScript
+    /// thread with the thrown value. Clones of this `Channel` created afterwards use
+    /// the same handler.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn log_errors(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let logger = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    ///     let mut channel = cx.channel();
+    ///
+    ///     channel.on_error(move |mut cx, err| {
+    ///         let logger = logger.to_inner(&mut cx);
+    ///         let this = cx.undefined();
+    ///
+    ///         logger.call(&mut cx, this, vec![err])?;
+    ///
+    ///         Ok(())
+    ///     });
+    ///
+    ///     channel.send(|mut cx| cx.throw_error("Oops"));
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn on_error<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: for<'a> Fn(TaskContext<'a>, Handle<'a, JsValue>) -> NeonResult<()>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.error_handler = Some(Arc::new(handler));
+        self
+    }
+
     /// Allow the Node event loop to exit while this `Channel` exists.
     /// _Idempotent_
     pub fn unref<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
@@ -103,31 +204,107 @@ impl Channel {
     }
 
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
-    /// Panics if there is a libuv error
+    /// Blocks while the queue of a [bounded](Channel::bounded) channel is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is a libuv error, or if the queue of a bounded channel is full
+    /// and `send` is called on the JavaScript thread. The queue is only drained by that
+    /// thread, so waiting for space there would block forever. Use
+    /// [`try_send`](Channel::try_send) to handle a full queue instead.
     pub fn send<F>(&self, f: F)
     where
         F: FnOnce(TaskContext) -> NeonResult<()> + Send + 'static,
     {
-        self.try_send(f).unwrap()
+        expect_scheduled(self.schedule(f, CallMode::Blocking))
     }
 
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
-    /// Returns an `Error` if the task could not be scheduled.
-    pub fn try_send<F>(&self, f: F) -> Result<(), SendError>
+    /// Returns an `Error` holding the closure if the task could not be scheduled, e.g.,
+    /// because the queue of a [bounded](Channel::bounded) channel is full.
+    pub fn try_send<F>(&self, f: F) -> Result<(), SendError<F>>
     where
         F: FnOnce(TaskContext) -> NeonResult<()> + Send + 'static,
     {
-        let callback = Box::new(move |env| {
-            let env = unsafe { std::mem::transmute(env) };
+        self.schedule(f, CallMode::NonBlocking)
+    }
 
-            // Note: It is sufficient to use `TaskContext`'s `InheritedHandleScope` because
-            // N-API creates a `HandleScope` before calling the callback.
-            TaskContext::with_context(env, move |cx| {
-                let _ = f(cx);
-            });
-        });
+    pub(crate) fn schedule<T: ChannelTask>(
+        &self,
+        task: T,
+        mode: CallMode,
+    ) -> Result<(), SendError<T>> {
+        self.state.schedule(task, self.error_handler.clone(), mode)
+    }
+
+    /// Schedules a closure to execute on the JavaScript thread that created this Channel
+    /// and returns a [`JoinHandle`] for its result. Blocks while the queue of a
+    /// [bounded](Channel::bounded) channel is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is a libuv error, or if the queue of a bounded channel is full
+    /// and `send_joinable` is called on the JavaScript thread, as with [`Channel::send`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn log_on_worker(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let get_level = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    ///     let channel = cx.channel();
+    ///
+    ///     std::thread::spawn(move || {
+    ///         // Call back to JavaScript and wait for the result
+    ///         let level = channel
+    ///             .send_joinable(move |mut cx| {
+    ///                 let get_level = get_level.into_inner(&mut cx);
+    ///                 let this = cx.undefined();
+    ///                 let args = Vec::<Handle<JsValue>>::new();
+    ///                 let level = get_level
+    ///                     .call(&mut cx, this, args)?
+    ///                     .downcast_or_throw::<JsNumber, _>(&mut cx)?;
+    ///
+    ///                 Ok(level.value(&mut cx))
+    ///             })
+    ///             .join();
+    ///
+    ///         if let Ok(level) = level {
+    ///             println!("Logging at level {}", level);
+    ///         }
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn send_joinable<T, F>(&self, f: F) -> JoinHandle<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(TaskContext) -> NeonResult<T> + Send + 'static,
+    {
+        let (sender, handle) = join_handle::new();
 
-        self.state.tsfn.call(callback, None).map_err(|_| SendError)
+        expect_scheduled(self.schedule(Joinable { f, sender }, CallMode::Blocking));
+
+        handle
+    }
+
+    /// Schedules a closure to execute on the JavaScript thread that created this Channel
+    /// and returns a [`JoinHandle`] for its result
+    /// Returns an `Error` holding the closure if the task could not be scheduled, e.g.,
+    /// because the queue of a [bounded](Channel::bounded) channel is full.
+    ///
+    /// If the closure throws, the exception is reported as with [`Channel::send`] and
+    /// the `JoinHandle` completes with a [`JoinError`].
+    pub fn try_send_joinable<T, F>(&self, f: F) -> Result<JoinHandle<T>, SendError<F>>
+    where
+        T: Send + 'static,
+        F: FnOnce(TaskContext) -> NeonResult<T> + Send + 'static,
+    {
+        let (sender, handle) = join_handle::new();
+
+        match self.schedule(Joinable { f, sender }, CallMode::NonBlocking) {
+            Ok(()) => Ok(handle),
+            Err(err) => Err(err.map(|joinable| joinable.f)),
+        }
     }
 
     /// Returns a boolean indicating if this `Channel` will prevent the Node event
@@ -153,6 +330,7 @@ impl Clone for Channel {
             return Self {
                 state: self.state.clone(),
                 has_ref: false,
+                error_handler: self.error_handler.clone(),
             };
         }
 
@@ -164,6 +342,7 @@ impl Clone for Channel {
         Self {
             state,
             has_ref: true,
+            error_handler: self.error_handler.clone(),
         }
     }
 }
@@ -192,45 +371,268 @@ impl Drop for Channel {
         // the `state` and it could be cloned in `try_send` and unref'ed on the
         // UV thread if strong reference count goes to 0.
         let state = Arc::clone(&self.state);
-
-        self.send(move |mut cx| {
+        let unref = move |mut cx: TaskContext| {
             state.unref(&mut cx);
             Ok(())
+        };
+
+        // Dropping must never block; the `Channel` may be dropped on the JavaScript
+        // thread. If the queue of a bounded channel is full, the unref is counted and
+        // scheduled by the JavaScript thread after it runs a queued closure. Scheduling
+        // fails otherwise only if the environment is being torn down, in which case
+        // there is nothing left to unref.
+        if let Err(err) = self.schedule(unref, CallMode::NonBlocking) {
+            if err.is_full() {
+                self.state.pending_unrefs.fetch_add(1, Ordering::AcqRel);
+
+                // The queue may have been drained since it was found full
+                self.state.schedule_pending_unrefs();
+            }
+        }
+    }
+}
+
+/// Panics if a closure could not be scheduled with `CallMode::Blocking`
+fn expect_scheduled<T>(result: Result<(), SendError<T>>) {
+    match result {
+        Ok(()) => {}
+        Err(err) if err.is_full() => panic!(
+            "Channel queue is full; the JavaScript thread can't wait for space, use `try_send`"
+        ),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Passes an exception left pending by a `Channel` closure to the error handler, or
+/// to Node as an uncaught exception
+fn report_exception(env: internal::Env, error_handler: Option<Arc<ErrorHandler>>) {
+    let mut err: raw::Local = std::ptr::null_mut();
+
+    if !unsafe { neon_runtime::error::catch_error(env.to_raw(), &mut err) } {
+        return;
+    }
+
+    if let Some(handler) = error_handler {
+        TaskContext::with_context(env, move |cx| {
+            let _ = handler(cx, JsValue::new_internal(err));
         });
+
+        // The handler threw
+        if !unsafe { neon_runtime::error::catch_error(env.to_raw(), &mut err) } {
+            return;
+        }
+    }
+
+    unsafe { neon_runtime::error::fatal_exception(env.to_raw(), err) };
+}
+
+/// A closure scheduled on a `Channel`, run with exceptions reported to the error
+/// handler
+trait Task: Send + 'static {
+    fn run(self: Box<Self>, env: Env);
+
+    /// Allows recovering the concrete task if it can't be scheduled
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send>;
+}
+
+/// Closure executed by a `Channel`
+pub(crate) trait ChannelTask: Send + 'static {
+    fn call(self, cx: TaskContext) -> NeonResult<()>;
+}
+
+impl<F> ChannelTask for F
+where
+    F: FnOnce(TaskContext) -> NeonResult<()> + Send + 'static,
+{
+    fn call(self, cx: TaskContext) -> NeonResult<()> {
+        self(cx)
+    }
+}
+
+/// Closure executed by a `Channel` that sends its result to a `JoinHandle`
+struct Joinable<F, T> {
+    f: F,
+    sender: join_handle::Sender<T>,
+}
+
+impl<F, T> ChannelTask for Joinable<F, T>
+where
+    T: Send + 'static,
+    F: FnOnce(TaskContext) -> NeonResult<T> + Send + 'static,
+{
+    fn call(self, cx: TaskContext) -> NeonResult<()> {
+        match (self.f)(cx) {
+            Ok(v) => {
+                self.sender.send(Ok(v));
+                Ok(())
+            }
+            Err(throw) => {
+                self.sender.send(Err(JoinError(JoinErrorKind::Threw)));
+                Err(throw)
+            }
+        }
+    }
+}
+
+struct Scheduled<T> {
+    task: T,
+    error_handler: Option<Arc<ErrorHandler>>,
+    /// State of a bounded channel, to schedule unrefs that found the queue full
+    state: Option<Weak<ChannelState>>,
+}
+
+impl<T: ChannelTask> Task for Scheduled<T> {
+    fn run(self: Box<Self>, env: Env) {
+        let Scheduled {
+            task,
+            error_handler,
+            state,
+        } = *self;
+        let env = unsafe { std::mem::transmute(env) };
+
+        // Note: It is sufficient to use `TaskContext`'s `InheritedHandleScope` because
+        // N-API creates a `HandleScope` before calling the callback.
+        TaskContext::with_context(env, move |cx| {
+            let _ = task.call(cx);
+        });
+
+        // The result is ignored since an exception is pending whether the closure
+        // returned `Err(Throw)` or swallowed the error
+        report_exception(env, error_handler);
+
+        // A closure was dequeued; there is space to schedule pending unrefs
+        if let Some(state) = state.and_then(|state| state.upgrade()) {
+            state.schedule_pending_unrefs();
+        }
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
+        self
     }
 }
 
 /// Error indicating that a closure was unable to be scheduled to execute on the event loop.
-pub struct SendError;
+///
+/// The error holds the closure, which may be retrieved with
+/// [`into_inner`](SendError::into_inner) to try again later.
+pub struct SendError<F = ()> {
+    is_full: bool,
+    closure: F,
+}
+
+impl<F> SendError<F> {
+    /// Returns `true` if the closure couldn't be scheduled because the queue of a
+    /// [bounded](Channel::bounded) channel is full. Otherwise, the channel is closed
+    /// because the JavaScript environment is shutting down.
+    pub fn is_full(&self) -> bool {
+        self.is_full
+    }
+
+    /// Returns the closure that couldn't be scheduled
+    pub fn into_inner(self) -> F {
+        self.closure
+    }
+
+    pub(crate) fn map<G>(self, f: impl FnOnce(F) -> G) -> SendError<G> {
+        SendError {
+            is_full: self.is_full,
+            closure: f(self.closure),
+        }
+    }
+}
 
-impl std::fmt::Display for SendError {
+impl<F> std::fmt::Display for SendError<F> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "SendError")
+        if self.is_full {
+            write!(f, "SendError: queue is full")
+        } else {
+            write!(f, "SendError")
+        }
     }
 }
 
-impl std::fmt::Debug for SendError {
+impl<F> std::fmt::Debug for SendError<F> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self, f)
     }
 }
 
-impl std::error::Error for SendError {}
+impl<F> std::error::Error for SendError<F> {}
 
 struct ChannelState {
     tsfn: ThreadsafeFunction<Callback>,
     ref_count: AtomicUsize,
+    /// Unrefs of dropped `Channel`s that could not be scheduled because the queue
+    /// of a bounded channel was full
+    pending_unrefs: AtomicUsize,
+    is_bounded: bool,
 }
 
 impl ChannelState {
-    fn new<'a, C: Context<'a>>(cx: &mut C) -> Self {
-        let tsfn = unsafe { ThreadsafeFunction::new(cx.env().to_raw(), Self::callback) };
+    fn new<'a, C: Context<'a>>(cx: &mut C, capacity: usize) -> Self {
+        let tsfn = unsafe {
+            ThreadsafeFunction::with_capacity(cx.env().to_raw(), capacity, Self::callback)
+        };
         Self {
             tsfn,
             ref_count: AtomicUsize::new(1),
+            pending_unrefs: AtomicUsize::new(0),
+            is_bounded: capacity > 0,
         }
     }
 
+    fn schedule<T: ChannelTask>(
+        self: &Arc<Self>,
+        task: T,
+        error_handler: Option<Arc<ErrorHandler>>,
+        mode: CallMode,
+    ) -> Result<(), SendError<T>> {
+        let callback = Box::new(Scheduled {
+            task,
+            error_handler,
+            state: if self.is_bounded {
+                Some(Arc::downgrade(self))
+            } else {
+                None
+            },
+        });
+
+        self.tsfn.call(callback, Some(mode)).map_err(|err| {
+            let is_full = err.is_queue_full();
+            let scheduled = err
+                .into_inner()
+                .into_any()
+                .downcast::<Scheduled<T>>()
+                .expect("Failed to recover the closure of a Channel task");
+
+            SendError {
+                is_full,
+                closure: scheduled.task,
+            }
+        })
+    }
+
+    /// Schedules the pending unrefs after the closures already in the queue. If the
+    /// queue is full again, a closure still in the queue schedules them once it runs.
+    fn schedule_pending_unrefs(self: &Arc<Self>) {
+        if self.pending_unrefs.load(Ordering::Acquire) == 0 {
+            return;
+        }
+
+        // Unrefs are only taken when the closure runs; closures scheduled
+        // concurrently find nothing left to unref
+        let state = Arc::clone(self);
+        let unref = move |mut cx: TaskContext| {
+            for _ in 0..state.pending_unrefs.swap(0, Ordering::AcqRel) {
+                state.unref(&mut cx);
+            }
+
+            Ok(())
+        };
+
+        let _ = self.schedule(unref, None, CallMode::NonBlocking);
+    }
+
     fn reference<'a, C: Context<'a>>(&self, cx: &mut C) {
         // We can use relaxed ordering because `reference()` can only be called
         // on the Event-Loop thread.
@@ -258,7 +660,7 @@ impl ChannelState {
     // Monomorphized trampoline funciton for calling the user provided closure
     fn callback(env: Option<Env>, callback: Callback) {
         if let Some(env) = env {
-            callback(env);
+            callback.run(env);
         } else {
             crate::context::internal::IS_RUNNING.with(|v| {
                 *v.borrow_mut() = false;
@@ -0,0 +1,154 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// Creates a connected sender and `JoinHandle`
+pub(super) fn new<T>() -> (Sender<T>, JoinHandle<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            result: None,
+            waker: None,
+        }),
+        ready: Condvar::new(),
+    });
+
+    let sender = Sender(Some(Arc::clone(&shared)));
+    let handle = JoinHandle { shared };
+
+    (sender, handle)
+}
+
+struct State<T> {
+    result: Option<Result<T, JoinError>>,
+    waker: Option<Waker>,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    ready: Condvar,
+}
+
+/// Completes a `JoinHandle`. If dropped before completing, e.g., because the
+/// closure was dropped without executing, the handle completes with an error.
+pub(super) struct Sender<T>(Option<Arc<Shared<T>>>);
+
+impl<T> Sender<T> {
+    pub(super) fn send(mut self, result: Result<T, JoinError>) {
+        if let Some(shared) = self.0.take() {
+            Self::complete(&shared, result);
+        }
+    }
+
+    fn complete(shared: &Shared<T>, result: Result<T, JoinError>) {
+        let waker = {
+            let mut state = shared.state.lock().unwrap();
+
+            state.result = Some(result);
+            state.waker.take()
+        };
+
+        shared.ready.notify_all();
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        if let Some(shared) = self.0.take() {
+            Self::complete(&shared, Err(JoinError(JoinErrorKind::Dropped)));
+        }
+    }
+}
+
+/// A handle to the result of a closure scheduled with
+/// [`Channel::send_joinable`](super::Channel::send_joinable).
+///
+/// The result may be awaited by blocking with [`join`](JoinHandle::join) or
+/// asynchronously, since `JoinHandle` implements [`Future`](std::future::Future).
+///
+/// Waiting for the result on the JavaScript thread that executes the closure
+/// deadlocks; a `JoinHandle` should only be joined on other threads.
+pub struct JoinHandle<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Blocks the current thread until the closure has executed and returns its
+    /// result
+    pub fn join(self) -> Result<T, JoinError> {
+        let mut state = self.shared.state.lock().unwrap();
+
+        loop {
+            if let Some(result) = state.result.take() {
+                return result;
+            }
+
+            state = self.shared.ready.wait(state).unwrap();
+        }
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.state.lock().unwrap();
+
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("JoinHandle")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum JoinErrorKind {
+    Threw,
+    Dropped,
+}
+
+/// Error indicating that a closure scheduled with
+/// [`Channel::send_joinable`](super::Channel::send_joinable) did not produce a value.
+///
+/// Either the closure threw an exception, which is reported as described in
+/// [`Channel`](super::Channel#exceptions), or it was dropped without executing
+/// because the JavaScript environment shut down.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct JoinError(pub(super) JoinErrorKind);
+
+impl JoinError {
+    /// Returns `true` if the closure threw an exception
+    pub fn is_throw(&self) -> bool {
+        self.0 == JoinErrorKind::Threw
+    }
+}
+
+impl std::fmt::Display for JoinError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            JoinErrorKind::Threw => f.write_str("Channel closure threw an exception"),
+            JoinErrorKind::Dropped => f.write_str("Channel closure was dropped without executing"),
+        }
+    }
+}
+
+impl std::fmt::Debug for JoinError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for JoinError {}
@@ -126,9 +126,18 @@
 #[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
 mod event_queue;
 
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+mod join_handle;
+
 #[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
 pub use self::event_queue::{Channel, SendError};
 
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+pub(crate) use self::event_queue::ChannelTask;
+
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+pub use self::join_handle::{JoinError, JoinHandle};
+
 #[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
 #[deprecated(since = "0.9.0", note = "Please use the Channel type instead")]
 #[doc(hidden)]
@@ -0,0 +1,121 @@
+//! Integration with the [Tokio](https://tokio.rs) runtime for executing Rust
+//! futures that settle JavaScript promises
+//!
+//! Each instance of a module (e.g., on the main thread and on each worker thread)
+//! lazily starts its own multi-threaded runtime the first time it is used. The
+//! runtime is shut down when the JavaScript environment of that instance is torn
+//! down; futures that have not yet completed are dropped and their promises are
+//! never settled.
+//!
+//! The runtime enables all drivers compiled into `tokio`. Enable additional
+//! `tokio` features (e.g., `time` or `net`) in the module's `Cargo.toml` to use them.
+
+use std::future::Future;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::task::Poll;
+
+pub use tokio::runtime::Handle;
+
+use crate::context::internal::ContextInternal;
+use crate::context::{Context, TaskContext};
+use crate::lifecycle::InstanceData;
+use crate::result::{JsResult, NeonResult};
+use crate::types::error::convert_panics;
+use crate::types::{JsPromise, Value};
+
+/// Tokio runtime owned by `InstanceData`
+pub(crate) struct Runtime(Option<tokio::runtime::Runtime>);
+
+impl Runtime {
+    pub(crate) fn new() -> io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Self(Some(runtime)))
+    }
+
+    pub(crate) fn handle(&self) -> &Handle {
+        // `unwrap` will not panic because the runtime is only taken in `drop`
+        self.0.as_ref().unwrap().handle()
+    }
+}
+
+impl Drop for Runtime {
+    // Dropping a `tokio::runtime::Runtime` blocks until all worker threads have
+    // stopped. The runtime is dropped on the JavaScript thread while the environment
+    // is torn down; do not block it on futures that may never yield.
+    fn drop(&mut self) {
+        if let Some(runtime) = self.0.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+/// Returns a handle to the Tokio runtime of the current module instance, starting
+/// the runtime if necessary.
+///
+/// Throws an `Error` if the runtime could not be started.
+pub fn handle<'a, C: Context<'a>>(cx: &mut C) -> NeonResult<Handle> {
+    match InstanceData::runtime(cx) {
+        Ok(handle) => Ok(handle),
+        Err(err) => cx.throw_error(format!("failed to start the Tokio runtime: {}", err)),
+    }
+}
+
+pub(crate) fn spawn_promise<'a, C, Fut, V, F>(
+    cx: &mut C,
+    future: Fut,
+    complete: F,
+) -> JsResult<'a, JsPromise>
+where
+    C: Context<'a>,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+    V: Value,
+    F: FnOnce(TaskContext, Fut::Output) -> JsResult<V> + Send + 'static,
+{
+    let runtime = handle(cx)?;
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    runtime.spawn(async move {
+        let output = CatchUnwind(Box::pin(future)).await;
+
+        // If the environment has been torn down, the closure is dropped and the
+        // promise is left pending along with it
+        let _ = deferred.try_settle_with(&channel, move |cx| {
+            let env = cx.env();
+
+            convert_panics(
+                env,
+                AssertUnwindSafe(move || match output {
+                    Ok(output) => complete(cx, output),
+                    Err(panic) => panic::resume_unwind(panic),
+                }),
+            )
+        });
+    });
+
+    Ok(promise)
+}
+
+/// Future that completes with an `Err` if polling the inner future panics. The
+/// panic is resumed on the JavaScript thread to reject the promise.
+struct CatchUnwind<F>(Pin<Box<F>>);
+
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = std::thread::Result<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        let future = self.0.as_mut();
+
+        match panic::catch_unwind(AssertUnwindSafe(move || future.poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Err(panic) => Poll::Ready(Err(panic)),
+        }
+    }
+}
@@ -65,8 +65,14 @@ pub use self::root::Root;
 use self::internal::SuperType;
 use crate::context::internal::Env;
 use crate::context::Context;
+#[cfg(feature = "napi-1")]
+use crate::object::Object;
 use crate::result::{JsResult, JsResultExt};
+#[cfg(feature = "napi-1")]
+use crate::result::{NeonResult, Throw};
 use crate::types::Value;
+#[cfg(feature = "napi-1")]
+use crate::types::{JsFunction, JsObject, JsString};
 use neon_runtime;
 use neon_runtime::raw;
 use std::error::Error;
@@ -236,6 +242,76 @@ impl<'a, T: Value> Handle<'a, T> {
         self.downcast(cx).or_throw(cx)
     }
 
+    #[cfg(feature = "napi-1")]
+    /// Tests whether this value is an instance of `constructor`, as with the JavaScript
+    /// `instanceof` operator. Unlike [`is_a`](Handle::is_a), this works with any
+    /// class, including classes defined in JavaScript.
+    ///
+    /// Throws if the check throws, e.g., from a custom `Symbol.hasInstance` method.
+    pub fn instance_of<'b, C: Context<'b>>(
+        &self,
+        cx: &mut C,
+        constructor: Handle<'b, JsFunction>,
+    ) -> NeonResult<bool> {
+        let mut result = false;
+
+        if unsafe {
+            neon_runtime::mem::instance_of(
+                &mut result,
+                cx.env().to_raw(),
+                self.to_raw(),
+                constructor.to_raw(),
+            )
+        } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Downcasts this value to an object after checking that it is an instance of
+    /// `constructor`, raising a JavaScript `TypeError` exception on failure.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::prelude::*;
+    /// // Expects an instance of the JavaScript class passed as the first argument
+    /// fn event_type(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let class = cx.argument::<JsFunction>(0)?;
+    ///     let event = cx
+    ///         .argument::<JsValue>(1)?
+    ///         .downcast_instance_or_throw(&mut cx, class)?;
+    ///
+    ///     event.get(&mut cx, "type")
+    /// }
+    /// ```
+    pub fn downcast_instance_or_throw<'b, C: Context<'b>>(
+        &self,
+        cx: &mut C,
+        constructor: Handle<'b, JsFunction>,
+    ) -> JsResult<'a, JsObject> {
+        // A custom `Symbol.hasInstance` may accept any value, including primitives
+        if self.instance_of(cx, constructor)? {
+            if let Ok(object) = self.downcast::<JsObject, _>(cx) {
+                return Ok(object);
+            }
+        }
+
+        let name = constructor
+            .get(cx, "name")?
+            .downcast::<JsString, _>(cx)
+            .map(|name| name.value(cx))
+            .unwrap_or_default();
+
+        if name.is_empty() {
+            cx.throw_type_error("expected an instance of the constructor")
+        } else {
+            cx.throw_type_error(format!("expected an instance of {}", name))
+        }
+    }
+
     #[cfg(feature = "napi-1")]
     pub fn strict_equals<'b, U: Value, C: Context<'b>>(
         &self,
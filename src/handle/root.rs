@@ -10,7 +10,7 @@ use neon_runtime::tsfn::ThreadsafeFunction;
 use crate::context::Context;
 use crate::handle::Handle;
 #[cfg(feature = "napi-6")]
-use crate::lifecycle::InstanceData;
+use crate::lifecycle::{DropData, InstanceData};
 use crate::object::Object;
 use crate::types::boxed::Finalize;
 
@@ -36,7 +36,7 @@ pub struct Root<T> {
     // It will *always* be `Some` when a user is interacting with `Root`.
     internal: Option<NapiRef>,
     #[cfg(feature = "napi-6")]
-    drop_queue: Arc<ThreadsafeFunction<NapiRef>>,
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
     _phantom: PhantomData<T>,
 }
 
@@ -190,7 +190,7 @@ impl<T> Drop for Root<T> {
     fn drop(&mut self) {
         // If `None`, the `NapiRef` has already been manually dropped
         if let Some(internal) = self.internal.take() {
-            let _ = self.drop_queue.call(internal.clone(), None);
+            let _ = self.drop_queue.call(DropData::Ref(internal), None);
         }
     }
 }
@@ -86,6 +86,8 @@ pub mod context;
     all(feature = "napi-4", feature = "event-queue-api")
 ))]
 pub mod event;
+#[cfg(all(feature = "napi-6", feature = "tokio-rt"))]
+pub mod executor;
 pub mod handle;
 pub mod meta;
 pub mod object;
@@ -93,7 +95,6 @@ pub mod prelude;
 #[cfg(feature = "napi-1")]
 pub mod reflect;
 pub mod result;
-#[cfg(feature = "legacy-runtime")]
 pub mod task;
 pub mod types;
 
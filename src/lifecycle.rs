@@ -8,6 +8,8 @@
 //!
 //! [napi-docs]: https://nodejs.org/api/n-api.html#n_api_environment_life_cycle_apis
 
+#[cfg(feature = "tokio-rt")]
+use std::io;
 use std::mem;
 use std::sync::Arc;
 
@@ -18,7 +20,10 @@ use neon_runtime::tsfn::ThreadsafeFunction;
 use crate::context::Context;
 #[cfg(all(feature = "event-queue-api"))]
 use crate::event::Channel;
+#[cfg(feature = "tokio-rt")]
+use crate::executor::{Handle, Runtime};
 use crate::handle::root::NapiRef;
+use crate::types::promise::NapiDeferred;
 
 /// `InstanceData` holds Neon data associated with a particular instance of a
 /// native module. If a module is loaded multiple times (e.g., worker threads), this
@@ -28,20 +33,34 @@ pub(crate) struct InstanceData {
     ///
     /// _Design Note_: An `Arc` ensures the `ThreadsafeFunction` outlives the unloading
     /// of a module. Since it is unlikely that modules will be re-loaded frequently, this
-    /// could be replaced with a leaked `&'static ThreadsafeFunction<NapiRef>`. However,
+    /// could be replaced with a leaked `&'static ThreadsafeFunction<DropData>`. However,
     /// given the cost of FFI, this optimization is omitted until the cost of an
     /// `Arc` is demonstrated as significant.
-    drop_queue: Arc<ThreadsafeFunction<NapiRef>>,
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
 
     /// Shared `Channel` that is cloned to be returned by the `cx.channel()` method
     #[cfg(all(feature = "event-queue-api"))]
     shared_channel: Channel,
+
+    /// Tokio runtime for `cx.spawn_promise()`, started on first use and shut down
+    /// when the environment is torn down
+    #[cfg(feature = "tokio-rt")]
+    runtime: Option<Runtime>,
+}
+
+/// Values that must be released on the JavaScript thread that created them
+pub(crate) enum DropData {
+    Deferred(NapiDeferred),
+    Ref(NapiRef),
 }
 
-fn drop_napi_ref(env: Option<Env>, data: NapiRef) {
+fn drop_data(env: Option<Env>, data: DropData) {
     if let Some(env) = env {
         unsafe {
-            reference::unreference(env, mem::transmute(data));
+            match data {
+                DropData::Deferred(data) => data.leaked(env),
+                DropData::Ref(data) => reference::unreference(env, mem::transmute(data)),
+            }
         }
     }
 }
@@ -63,7 +82,7 @@ impl InstanceData {
         }
 
         let drop_queue = unsafe {
-            let queue = ThreadsafeFunction::new(env, drop_napi_ref);
+            let queue = ThreadsafeFunction::new(env, drop_data);
             queue.unref(env);
             queue
         };
@@ -79,13 +98,15 @@ impl InstanceData {
             drop_queue: Arc::new(drop_queue),
             #[cfg(all(feature = "event-queue-api"))]
             shared_channel,
+            #[cfg(feature = "tokio-rt")]
+            runtime: None,
         };
 
         unsafe { &mut *neon_runtime::lifecycle::set_instance_data(env, data) }
     }
 
     /// Helper to return a reference to the `drop_queue` field of `InstanceData`
-    pub(crate) fn drop_queue<'a, C: Context<'a>>(cx: &mut C) -> Arc<ThreadsafeFunction<NapiRef>> {
+    pub(crate) fn drop_queue<'a, C: Context<'a>>(cx: &mut C) -> Arc<ThreadsafeFunction<DropData>> {
         Arc::clone(&InstanceData::get(cx).drop_queue)
     }
 
@@ -97,4 +118,21 @@ impl InstanceData {
         channel.reference(cx);
         channel
     }
+
+    /// Returns a handle to the Tokio runtime of this instance, starting it if necessary
+    #[cfg(feature = "tokio-rt")]
+    pub(crate) fn runtime<'a, C: Context<'a>>(cx: &mut C) -> io::Result<Handle> {
+        let data = InstanceData::get(cx);
+
+        if let Some(runtime) = &data.runtime {
+            return Ok(runtime.handle().clone());
+        }
+
+        let runtime = Runtime::new()?;
+        let handle = runtime.handle().clone();
+
+        data.runtime = Some(runtime);
+
+        Ok(handle)
+    }
 }
@@ -0,0 +1,213 @@
+//! Support for classes defined with `#[neon::class]`.
+
+use std::any;
+use std::cell::RefCell;
+use std::os::raw::c_void;
+
+use neon_runtime::raw;
+
+use crate::context::internal::{ContextInternal, Env};
+use crate::context::{CallKind, CallbackInfo, Context, FunctionContext};
+use crate::handle::Managed;
+use crate::object::wrap::{self, Class};
+use crate::result::{JsResult, NeonResult};
+use crate::types::error::convert_panics;
+use crate::types::internal::{Callback, FunctionCallback};
+use crate::types::{build, JsFunction, JsObject, JsValue};
+
+/// Method, getter or static method of a class.
+pub type Method = fn(FunctionContext) -> JsResult<JsValue>;
+
+/// Setter of a class property. The value is the first argument.
+pub type Setter = fn(FunctionContext) -> NeonResult<()>;
+
+/// A property of a class, named by its JavaScript name.
+pub enum Property {
+    Method(&'static str, Method),
+    Accessor(&'static str, Option<Method>, Option<Setter>),
+    Static(&'static str, Method),
+}
+
+impl Property {
+    fn name(&self) -> &'static str {
+        match self {
+            Property::Method(name, _) => name,
+            Property::Accessor(name, _, _) => name,
+            Property::Static(name, _) => name,
+        }
+    }
+}
+
+/// Defines the class `T` with a constructor and properties.
+pub fn define<'a, T: Class, C: Context<'a>>(
+    cx: &mut C,
+    name: &str,
+    constructor: fn(FunctionContext) -> JsResult<JsObject>,
+    properties: &'static [Property],
+) -> JsResult<'a, JsFunction> {
+    let descriptors = properties
+        .iter()
+        .map(|property| {
+            let mut descriptor = neon_runtime::property::Descriptor {
+                name: cx.string(property.name()).to_raw(),
+                data: property as *const Property as *mut c_void,
+                ..Default::default()
+            };
+
+            match property {
+                Property::Method(..) => {
+                    descriptor.method = MethodCallback(property).into_c_callback().static_callback;
+                    descriptor.attributes.writable = true;
+                    descriptor.attributes.configurable = true;
+                }
+                Property::Accessor(_, getter, setter) => {
+                    if getter.is_some() {
+                        descriptor.getter =
+                            GetterCallback(property).into_c_callback().static_callback;
+                    }
+
+                    if setter.is_some() {
+                        descriptor.setter =
+                            SetterCallback(property).into_c_callback().static_callback;
+                    }
+
+                    descriptor.attributes.configurable = true;
+                }
+                Property::Static(..) => {
+                    descriptor.method = MethodCallback(property).into_c_callback().static_callback;
+                    descriptor.attributes.writable = true;
+                    descriptor.attributes.configurable = true;
+                    descriptor.attributes.is_static = true;
+                }
+            }
+
+            descriptor
+        })
+        .collect::<Vec<_>>();
+
+    let env = cx.env();
+
+    build(env, |out| unsafe {
+        let constructor = FunctionCallback(constructor).into_c_callback();
+
+        neon_runtime::class::define(out, env.to_raw(), name, constructor, &descriptors)
+    })
+}
+
+/// Calls the Rust constructor `f` of class `T` and wraps the result in `this`.
+/// Throws a `TypeError` if the constructor was called without `new`.
+pub fn construct<'a, T: Class>(
+    mut cx: FunctionContext<'a>,
+    f: fn(FunctionContext) -> NeonResult<T>,
+) -> JsResult<'a, JsObject> {
+    if let CallKind::Call = cx.kind() {
+        return cx.throw_type_error(format!(
+            "Class constructor {} cannot be invoked without 'new'",
+            class_name::<T>()
+        ));
+    }
+
+    let env = cx.env();
+    let this = cx.this();
+    let value = f(cx)?;
+
+    // `this` is a newly created object and cannot already be wrapped
+    assert!(
+        wrap::wrap(env, this.to_raw(), RefCell::new(value)),
+        "Failed to wrap class instance"
+    );
+
+    Ok(this)
+}
+
+/// Returns the value of class `T` wrapped in `this`. Throws a `TypeError` if
+/// `this` is not an instance of `T`.
+pub fn this<'a, T: Class>(cx: &mut FunctionContext<'a>) -> NeonResult<&'a RefCell<T>> {
+    let this = cx.this();
+
+    match unsafe { wrap::unwrap::<RefCell<T>>(cx.env(), this.to_raw()) } {
+        Some(value) => Ok(value),
+        None => cx.throw_type_error(format!(
+            "Illegal invocation: `this` is not an instance of {}",
+            class_name::<T>()
+        )),
+    }
+}
+
+fn class_name<T>() -> &'static str {
+    let name = any::type_name::<T>();
+
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Calls a class method. The `Property` is passed as the callback data.
+struct MethodCallback(&'static Property);
+
+/// Calls the getter of a class accessor. The `Property` is passed as the callback data.
+struct GetterCallback(&'static Property);
+
+/// Calls the setter of a class accessor. The `Property` is passed as the callback data.
+struct SetterCallback(&'static Property);
+
+unsafe fn property<'a>(env: Env, info: &CallbackInfo<'_>) -> &'a Property {
+    &*(info.data(env) as *const Property)
+}
+
+fn call_method(env: Env, cx: FunctionContext, method: Method) -> raw::Local {
+    match convert_panics(env, || method(cx)) {
+        Ok(value) => value.to_raw(),
+        // An exception is pending; see `FunctionCallback`
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+impl Callback<raw::Local> for MethodCallback {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            info.with_cx::<JsObject, _, _>(env, |cx| match property(env, &info) {
+                Property::Method(_, method) | Property::Static(_, method) => {
+                    call_method(env, cx, *method)
+                }
+                Property::Accessor(..) => unreachable!(),
+            })
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        self.0 as *const Property as *mut _
+    }
+}
+
+impl Callback<raw::Local> for GetterCallback {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            info.with_cx::<JsObject, _, _>(env, |cx| match property(env, &info) {
+                Property::Accessor(_, Some(getter), _) => call_method(env, cx, *getter),
+                _ => unreachable!(),
+            })
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        self.0 as *const Property as *mut _
+    }
+}
+
+impl Callback<raw::Local> for SetterCallback {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            info.with_cx::<JsObject, _, _>(env, |cx| match property(env, &info) {
+                Property::Accessor(_, _, Some(setter)) => {
+                    let _ = convert_panics(env, || setter(cx));
+
+                    std::ptr::null_mut()
+                }
+                _ => unreachable!(),
+            })
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        self.0 as *const Property as *mut _
+    }
+}
@@ -8,6 +8,9 @@ pub use crate::object::class::internal::{
     AllocateCallback, ConstructCallback, ConstructorCallCallback, MethodCallback,
 };
 
+#[cfg(feature = "napi-1")]
+pub mod class;
+
 // An alias for neon_runtime so macros can refer to it.
 pub mod runtime {
     pub use neon_runtime::*;
@@ -0,0 +1,130 @@
+//! Options for enumerating property keys with
+//! [`Object::get_property_names`](crate::object::Object::get_property_names).
+
+/// Whether to collect the keys of an object's prototype chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyCollectionMode {
+    /// Collect the keys of the object and its prototypes, as with `for...in`
+    IncludePrototypes,
+    /// Collect only the object's own keys, as with `Reflect.ownKeys`
+    OwnOnly,
+}
+
+/// How to represent integer index keys, such as the indices of an array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyConversion {
+    /// Return indices as numbers
+    KeepNumbers,
+    /// Return indices as strings, as with `Object.keys`
+    NumbersToStrings,
+}
+
+/// Selects the property keys of an object to collect.
+///
+/// By default, all own keys are collected, including symbols and non-enumerable
+/// properties, with indices converted to strings. Filters exclude properties that
+/// don't have an attribute, e.g., [`enumerable`](KeyOptions::enumerable) skips
+/// non-enumerable properties.
+///
+/// ```
+/// # #[cfg(feature = "napi-6")] {
+/// # use neon::prelude::*;
+/// use neon::object::{KeyCollectionMode, KeyOptions};
+///
+/// // The keys visited by a `for...in` loop
+/// fn for_in_keys(mut cx: FunctionContext) -> JsResult<JsArray> {
+///     let obj = cx.argument::<JsObject>(0)?;
+///     let options = KeyOptions::new()
+///         .mode(KeyCollectionMode::IncludePrototypes)
+///         .enumerable(true)
+///         .skip_symbols(true);
+///
+///     obj.get_property_names(&mut cx, options)
+/// }
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct KeyOptions {
+    mode: KeyCollectionMode,
+    conversion: KeyConversion,
+    writable: bool,
+    enumerable: bool,
+    configurable: bool,
+    skip_strings: bool,
+    skip_symbols: bool,
+}
+
+impl KeyOptions {
+    /// Collects all own keys of an object
+    pub fn new() -> Self {
+        KeyOptions {
+            mode: KeyCollectionMode::OwnOnly,
+            conversion: KeyConversion::NumbersToStrings,
+            writable: false,
+            enumerable: false,
+            configurable: false,
+            skip_strings: false,
+            skip_symbols: false,
+        }
+    }
+
+    /// Sets whether to collect the keys of prototypes
+    pub fn mode(mut self, mode: KeyCollectionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets how to represent integer index keys
+    pub fn conversion(mut self, conversion: KeyConversion) -> Self {
+        self.conversion = conversion;
+        self
+    }
+
+    /// Sets whether to only collect writable properties
+    pub fn writable(mut self, writable: bool) -> Self {
+        self.writable = writable;
+        self
+    }
+
+    /// Sets whether to only collect enumerable properties
+    pub fn enumerable(mut self, enumerable: bool) -> Self {
+        self.enumerable = enumerable;
+        self
+    }
+
+    /// Sets whether to only collect configurable properties
+    pub fn configurable(mut self, configurable: bool) -> Self {
+        self.configurable = configurable;
+        self
+    }
+
+    /// Sets whether to skip string keys
+    pub fn skip_strings(mut self, skip_strings: bool) -> Self {
+        self.skip_strings = skip_strings;
+        self
+    }
+
+    /// Sets whether to skip symbol keys
+    pub fn skip_symbols(mut self, skip_symbols: bool) -> Self {
+        self.skip_symbols = skip_symbols;
+        self
+    }
+
+    pub(crate) fn to_raw(self) -> neon_runtime::object::KeyOptions {
+        neon_runtime::object::KeyOptions {
+            include_prototypes: self.mode == KeyCollectionMode::IncludePrototypes,
+            writable: self.writable,
+            enumerable: self.enumerable,
+            configurable: self.configurable,
+            skip_strings: self.skip_strings,
+            skip_symbols: self.skip_symbols,
+            numbers_to_strings: self.conversion == KeyConversion::NumbersToStrings,
+        }
+    }
+}
+
+impl Default for KeyOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -8,11 +8,10 @@
 //! ## Property Keys
 //!
 //! Object properties are accessed by a _property key_, which in JavaScript
-//! can be a string or [symbol][symbol]. (Neon does not yet have support for
-//! symbols.) For convenience, the [`PropertyKey`](PropertyKey) trait allows
-//! Neon programs to use various Rust string types, as well as numeric types,
-//! as keys when accessing object properties, converting the keys to strings
-//! as necessary:
+//! can be a string or [symbol][symbol]. For convenience, the
+//! [`PropertyKey`](PropertyKey) trait allows Neon programs to use various Rust
+//! string types, as well as numeric types, as keys when accessing object
+//! properties, converting the keys to strings as necessary:
 //!
 //! ```
 //! # #[cfg(feature = "napi-1")] {
@@ -31,15 +30,47 @@
 //! # }
 //! ```
 //!
+//! Any JavaScript value handle can also be used as a key. In particular, a
+//! [`JsSymbol`](crate::types::JsSymbol) handle accesses a symbol-keyed property:
+//!
+//! ```
+//! # #[cfg(feature = "napi-1")] {
+//! # use neon::prelude::*;
+//! use neon::types::JsSymbol;
+//!
+//! fn get_iterator<'a>(
+//!     cx: &mut impl Context<'a>,
+//!     obj: Handle<'a, JsObject>
+//! ) -> JsResult<'a, JsValue> {
+//!     let key = JsSymbol::iterator(cx)?;
+//!     obj.get(cx, key)
+//! }
+//! # }
+//! ```
+//!
 //! [hierarchy]: crate::types#the-javascript-type-hierarchy
 //! [symbol]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol
 
 #[cfg(feature = "legacy-runtime")]
 pub(crate) mod class;
+#[cfg(feature = "napi-6")]
+pub(crate) mod keys;
+#[cfg(feature = "napi-1")]
+pub(crate) mod property;
+#[cfg(feature = "napi-1")]
+pub(crate) mod wrap;
 
 #[cfg(feature = "legacy-runtime")]
 pub use self::class::{Class, ClassDescriptor};
+#[cfg(feature = "napi-6")]
+pub use self::keys::{KeyCollectionMode, KeyConversion, KeyOptions};
+#[cfg(feature = "napi-1")]
+pub use self::property::PropertyDescriptor;
+#[cfg(feature = "napi-5")]
+pub use self::property::{Getter, Setter};
 pub use self::traits::*;
+#[cfg(feature = "napi-1")]
+pub use self::wrap::{Class, UnwrapError};
 
 #[cfg(feature = "legacy-runtime")]
 mod traits {
@@ -129,16 +160,19 @@ mod traits {
 
 #[cfg(feature = "napi-1")]
 mod traits {
+    use super::property::{self, PropertyDescriptor};
+    use super::wrap::{self, UnwrapError};
     use crate::context::internal::Env;
     use crate::context::Context;
     use crate::handle::{Handle, Managed, Root};
-    use crate::result::{NeonResult, Throw};
+    use crate::result::{JsResult, NeonResult, Throw};
+    use crate::types::boxed::Finalize;
     use crate::types::utf8::Utf8;
     use crate::types::{build, JsValue, Value};
     use neon_runtime::raw;
 
     #[cfg(feature = "napi-6")]
-    use crate::result::JsResult;
+    use super::keys::KeyOptions;
     #[cfg(feature = "napi-6")]
     use crate::types::JsArray;
 
@@ -158,6 +192,10 @@ mod traits {
             obj: raw::Local,
             val: raw::Local,
         ) -> bool;
+
+        /// Mutates `out` to refer to the key as a JavaScript value. Numeric keys are
+        /// converted to strings.
+        unsafe fn to_local<'c, C: Context<'c>>(self, cx: &mut C, out: &mut raw::Local) -> bool;
     }
 
     impl PropertyKey for u32 {
@@ -179,6 +217,10 @@ mod traits {
         ) -> bool {
             neon_runtime::object::set_index(out, cx.env().to_raw(), obj, self, val)
         }
+
+        unsafe fn to_local<'c, C: Context<'c>>(self, cx: &mut C, out: &mut raw::Local) -> bool {
+            self.to_string().as_str().to_local(cx, out)
+        }
     }
 
     impl<'a, K: Value> PropertyKey for Handle<'a, K> {
@@ -204,6 +246,11 @@ mod traits {
 
             neon_runtime::object::set(out, env, obj, self.to_raw(), val)
         }
+
+        unsafe fn to_local<'c, C: Context<'c>>(self, _cx: &mut C, out: &mut raw::Local) -> bool {
+            *out = self.to_raw();
+            true
+        }
     }
 
     impl<'a> PropertyKey for &'a str {
@@ -231,6 +278,12 @@ mod traits {
 
             neon_runtime::object::set_string(env, out, obj, ptr, len, val)
         }
+
+        unsafe fn to_local<'c, C: Context<'c>>(self, cx: &mut C, out: &mut raw::Local) -> bool {
+            let (ptr, len) = Utf8::from(self).into_small_unwrap().lower();
+
+            neon_runtime::string::new(out, cx.env().to_raw(), ptr, len)
+        }
     }
 
     /// The trait of all object types.
@@ -245,6 +298,93 @@ mod traits {
             })
         }
 
+        /// Returns whether this object or its prototype chain has a property named by
+        /// `key`, as with the `in` operator.
+        fn has<'a, C: Context<'a>, K: PropertyKey>(self, cx: &mut C, key: K) -> NeonResult<bool> {
+            let key = property::key_to_local(cx, key)?;
+            let mut result = false;
+
+            if unsafe {
+                neon_runtime::object::has(&mut result, cx.env().to_raw(), self.to_raw(), key)
+            } {
+                Ok(result)
+            } else {
+                Err(Throw)
+            }
+        }
+
+        /// Returns whether this object has an own property named by `key`, as with
+        /// `Object.prototype.hasOwnProperty`. Throws a `TypeError` if the key is a
+        /// handle to a value other than a string or symbol.
+        fn has_own<'a, C: Context<'a>, K: PropertyKey>(
+            self,
+            cx: &mut C,
+            key: K,
+        ) -> NeonResult<bool> {
+            let key = property::key_to_name(cx, key)?;
+            let mut result = false;
+
+            if unsafe {
+                neon_runtime::object::has_own(&mut result, cx.env().to_raw(), self.to_raw(), key)
+            } {
+                Ok(result)
+            } else {
+                Err(Throw)
+            }
+        }
+
+        /// Deletes the property named by `key`, as with the `delete` operator. Returns
+        /// `false` if the property is non-configurable and was not deleted; deleting a
+        /// property that does not exist succeeds.
+        fn delete<'a, C: Context<'a>, K: PropertyKey>(
+            self,
+            cx: &mut C,
+            key: K,
+        ) -> NeonResult<bool> {
+            let key = property::key_to_local(cx, key)?;
+            let mut result = false;
+
+            if unsafe {
+                neon_runtime::object::delete(&mut result, cx.env().to_raw(), self.to_raw(), key)
+            } {
+                Ok(result)
+            } else {
+                Err(Throw)
+            }
+        }
+
+        /// Returns the prototype of this object, which is `null` for objects without
+        /// a prototype, as with `Object.getPrototypeOf`.
+        fn get_prototype<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsValue> {
+            let env = cx.env();
+
+            build(env, |out| unsafe {
+                neon_runtime::object::get_prototype(out, env.to_raw(), self.to_raw())
+            })
+        }
+
+        /// Freezes this object, as with `Object.freeze`. Properties can no longer be
+        /// added, removed or changed.
+        #[cfg(feature = "napi-8")]
+        fn freeze<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<()> {
+            if unsafe { neon_runtime::object::freeze(cx.env().to_raw(), self.to_raw()) } {
+                Ok(())
+            } else {
+                Err(Throw)
+            }
+        }
+
+        /// Seals this object, as with `Object.seal`. Properties can no longer be added
+        /// or removed, but writable properties can still be changed.
+        #[cfg(feature = "napi-8")]
+        fn seal<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<()> {
+            if unsafe { neon_runtime::object::seal(cx.env().to_raw(), self.to_raw()) } {
+                Ok(())
+            } else {
+                Err(Throw)
+            }
+        }
+
         #[cfg(feature = "napi-6")]
         fn get_own_property_names<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsArray> {
             let env = cx.env();
@@ -254,6 +394,47 @@ mod traits {
             })
         }
 
+        /// Returns the property keys of this object selected by `options` as an array.
+        /// Throws if the object is a proxy that throws while enumerating its keys.
+        #[cfg(feature = "napi-6")]
+        fn get_property_names<'a, C: Context<'a>>(
+            self,
+            cx: &mut C,
+            options: KeyOptions,
+        ) -> JsResult<'a, JsArray> {
+            let env = cx.env();
+
+            build(cx.env(), |out| unsafe {
+                neon_runtime::object::get_all_property_names(
+                    out,
+                    env.to_raw(),
+                    self.to_raw(),
+                    options.to_raw(),
+                )
+            })
+        }
+
+        /// Returns the keys of this object selected by `options`, paired with their
+        /// values. With [`KeyOptions::enumerable`] and
+        /// [`KeyOptions::skip_symbols`], this is equivalent to `Object.entries`.
+        #[cfg(feature = "napi-6")]
+        fn entries<'a, C: Context<'a>>(
+            self,
+            cx: &mut C,
+            options: KeyOptions,
+        ) -> NeonResult<Vec<(Handle<'a, JsValue>, Handle<'a, JsValue>)>> {
+            let keys = self.get_property_names(cx, options)?.to_vec(cx)?;
+            let mut entries = Vec::with_capacity(keys.len());
+
+            for key in keys {
+                let value = self.get(cx, key)?;
+
+                entries.push((key, value));
+            }
+
+            Ok(entries)
+        }
+
         fn set<'a, C: Context<'a>, K: PropertyKey, W: Value>(
             self,
             cx: &mut C,
@@ -268,9 +449,128 @@ mod traits {
             }
         }
 
+        /// Defines a property on this object, as with `Object.defineProperty`. Throws a
+        /// `TypeError` if the property can't be defined, e.g., because it is an existing
+        /// non-configurable property, or if the key is a handle to a value other than
+        /// a string or symbol. A [`JsBox`](crate::types::JsBox) is not extensible;
+        /// define properties on an object holding the box instead.
+        ///
+        /// ```
+        /// # use neon::prelude::*;
+        /// use neon::object::PropertyDescriptor;
+        ///
+        /// fn constants(mut cx: FunctionContext) -> JsResult<JsObject> {
+        ///     let obj = cx.empty_object();
+        ///     let answer = cx.number(42);
+        ///
+        ///     // A read-only, enumerable constant
+        ///     obj.define_property(
+        ///         &mut cx,
+        ///         "ANSWER",
+        ///         PropertyDescriptor::value(answer).enumerable(true),
+        ///     )?;
+        ///
+        ///     Ok(obj)
+        /// }
+        /// ```
+        fn define_property<'a, 'b, C: Context<'a>, K: PropertyKey>(
+            self,
+            cx: &mut C,
+            key: K,
+            descriptor: PropertyDescriptor<'b>,
+        ) -> NeonResult<()> {
+            self.define_properties(cx, Some((key, descriptor)))
+        }
+
+        /// Defines several properties on this object at once, as with
+        /// `Object.defineProperties`. Throws a `TypeError` if any property can't be
+        /// defined.
+        fn define_properties<'a, 'b, C, K, I>(self, cx: &mut C, properties: I) -> NeonResult<()>
+        where
+            C: Context<'a>,
+            K: PropertyKey,
+            I: IntoIterator<Item = (K, PropertyDescriptor<'b>)>,
+        {
+            property::define(cx, self.to_raw(), properties)
+        }
+
         fn root<'a, C: Context<'a>>(&self, cx: &mut C) -> Root<Self> {
             Root::new(cx, self)
         }
+
+        /// Attaches a Rust value to this object. The value is finalized when the
+        /// object is garbage collected, unless it is first removed with
+        /// [`remove_wrap`](Object::remove_wrap).
+        ///
+        /// Unlike [`JsBox`](crate::types::JsBox), this can attach state to any
+        /// object, such as an instance of a class defined in JavaScript. An object
+        /// holds at most one wrapped value; throws a `TypeError` if the object
+        /// already has one.
+        ///
+        /// ```
+        /// # use neon::prelude::*;
+        /// struct Connection {
+        ///     id: u32,
+        /// }
+        ///
+        /// impl Finalize for Connection {}
+        ///
+        /// fn init_connection(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+        ///     let this = cx.this();
+        ///     let id = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+        ///
+        ///     this.wrap(&mut cx, Connection { id })?;
+        ///
+        ///     Ok(cx.undefined())
+        /// }
+        ///
+        /// fn connection_id(mut cx: FunctionContext) -> JsResult<JsNumber> {
+        ///     let this = cx.this();
+        ///     let id = match this.unwrap::<Connection, _>(&mut cx) {
+        ///         Ok(connection) => connection.id,
+        ///         Err(err) => return cx.throw_type_error(err.to_string()),
+        ///     };
+        ///
+        ///     Ok(cx.number(id))
+        /// }
+        /// ```
+        fn wrap<'a, T: Finalize + Send + 'static, C: Context<'a>>(
+            self,
+            cx: &mut C,
+            value: T,
+        ) -> NeonResult<()> {
+            if wrap::wrap(cx.env(), self.to_raw(), value) {
+                Ok(())
+            } else {
+                cx.throw_type_error("Object is already wrapped")
+            }
+        }
+
+        /// Returns a reference to the Rust value of type `T` attached to this object
+        /// with [`wrap`](Object::wrap). Fails if the object is not wrapped, wraps a
+        /// value of another type or was wrapped by another native module.
+        fn unwrap<'a, T: Send + 'static, C: Context<'a>>(
+            self,
+            cx: &mut C,
+        ) -> Result<&'a T, UnwrapError<T>> {
+            unsafe { wrap::unwrap(cx.env(), self.to_raw()) }.ok_or_else(UnwrapError::new)
+        }
+
+        /// Detaches the Rust value of type `T` attached to this object with
+        /// [`wrap`](Object::wrap) and returns it without finalizing it. Returns `None`,
+        /// leaving the object unchanged, if the object is not wrapped, wraps a value
+        /// of another type or was wrapped by another native module.
+        ///
+        /// # Safety
+        ///
+        /// No reference to the value returned by [`unwrap`](Object::unwrap) may be
+        /// used after the value is removed.
+        unsafe fn remove_wrap<'a, T: Send + 'static, C: Context<'a>>(
+            self,
+            cx: &mut C,
+        ) -> Option<T> {
+            wrap::remove_wrap(cx.env(), self.to_raw())
+        }
     }
 
     /// The trait of types that can be a function's `this` binding.
@@ -0,0 +1,290 @@
+//! Descriptors for defining properties with
+//! [`Object::define_property`](crate::object::Object::define_property).
+
+#[cfg(feature = "napi-5")]
+use std::mem;
+#[cfg(feature = "napi-5")]
+use std::os::raw::c_void;
+
+use neon_runtime::raw;
+
+use crate::context::Context;
+#[cfg(feature = "napi-5")]
+use crate::context::{internal::Env, CallbackInfo, FunctionContext};
+use crate::handle::{Handle, Managed};
+use crate::object::PropertyKey;
+#[cfg(feature = "napi-5")]
+use crate::result::JsResult;
+use crate::result::{NeonResult, Throw};
+#[cfg(feature = "napi-5")]
+use crate::types::error::convert_panics;
+#[cfg(feature = "napi-5")]
+use crate::types::internal::Callback;
+#[cfg(feature = "napi-5")]
+use crate::types::JsObject;
+use crate::types::{JsValue, Value};
+
+/// Getter of an accessor property. The object is the `this` binding.
+#[cfg(feature = "napi-5")]
+pub type Getter = fn(FunctionContext) -> JsResult<JsValue>;
+
+/// Setter of an accessor property. The object is the `this` binding and the value
+/// is the first argument.
+#[cfg(feature = "napi-5")]
+pub type Setter = fn(FunctionContext) -> NeonResult<()>;
+
+#[derive(Clone, Copy)]
+enum Kind<'a> {
+    Value(Handle<'a, JsValue>),
+    #[cfg(feature = "napi-5")]
+    Accessor(Accessor),
+}
+
+#[cfg(feature = "napi-5")]
+#[derive(Clone, Copy)]
+struct Accessor {
+    getter: Option<Getter>,
+    setter: Option<Setter>,
+}
+
+/// Describes a property defined with
+/// [`Object::define_property`](crate::object::Object::define_property).
+///
+/// A property either holds a value or is an accessor property computed by Rust
+/// functions. As with `Object.defineProperty`, all attributes default to `false`;
+/// a property is read-only, hidden from enumeration and can't be deleted or
+/// redefined unless otherwise specified.
+///
+/// ```
+/// # #[cfg(feature = "napi-5")] {
+/// # use neon::prelude::*;
+/// use neon::object::PropertyDescriptor;
+///
+/// fn now(mut cx: FunctionContext) -> JsResult<JsValue> {
+///     let now = std::time::SystemTime::now()
+///         .duration_since(std::time::UNIX_EPOCH)
+///         .unwrap();
+///
+///     Ok(cx.number(now.as_millis() as f64).upcast())
+/// }
+///
+/// fn clock(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let obj = cx.empty_object();
+///
+///     obj.define_property(&mut cx, "now", PropertyDescriptor::getter(now))?;
+///
+///     Ok(obj)
+/// }
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct PropertyDescriptor<'a> {
+    kind: Kind<'a>,
+    writable: bool,
+    enumerable: bool,
+    configurable: bool,
+}
+
+impl<'a> PropertyDescriptor<'a> {
+    fn new(kind: Kind<'a>) -> Self {
+        PropertyDescriptor {
+            kind,
+            writable: false,
+            enumerable: false,
+            configurable: false,
+        }
+    }
+
+    /// Describes a property holding `value`
+    pub fn value<V: Value>(value: Handle<'a, V>) -> Self {
+        Self::new(Kind::Value(value.upcast()))
+    }
+
+    /// Describes a read-only accessor property computed by `getter`
+    #[cfg(feature = "napi-5")]
+    pub fn getter(getter: Getter) -> Self {
+        Self::accessor(Some(getter), None)
+    }
+
+    /// Describes an accessor property with an optional `getter` and `setter`. Reading
+    /// a property without a getter returns `undefined`; assigning to a property
+    /// without a setter is ignored, or throws in strict mode.
+    #[cfg(feature = "napi-5")]
+    pub fn accessor(getter: Option<Getter>, setter: Option<Setter>) -> Self {
+        Self::new(Kind::Accessor(Accessor { getter, setter }))
+    }
+
+    /// Sets whether the value may be changed by assignment. Ignored by accessor
+    /// properties.
+    pub fn writable(mut self, writable: bool) -> Self {
+        self.writable = writable;
+        self
+    }
+
+    /// Sets whether the property is visible to enumeration, e.g., in `Object.keys`
+    pub fn enumerable(mut self, enumerable: bool) -> Self {
+        self.enumerable = enumerable;
+        self
+    }
+
+    /// Sets whether the property may be deleted or redefined
+    pub fn configurable(mut self, configurable: bool) -> Self {
+        self.configurable = configurable;
+        self
+    }
+}
+
+/// Converts `key` to a JavaScript value
+pub(crate) fn key_to_local<'a, C: Context<'a>, K: PropertyKey>(
+    cx: &mut C,
+    key: K,
+) -> NeonResult<raw::Local> {
+    let mut local: raw::Local = std::ptr::null_mut();
+
+    if unsafe { key.to_local(cx, &mut local) } {
+        Ok(local)
+    } else {
+        Err(Throw)
+    }
+}
+
+/// Converts `key` to a property name, a string or symbol. Throws a `TypeError` if
+/// `key` is a handle to any other value.
+pub(crate) fn key_to_name<'a, C: Context<'a>, K: PropertyKey>(
+    cx: &mut C,
+    key: K,
+) -> NeonResult<raw::Local> {
+    let local = key_to_local(cx, key)?;
+    let env = cx.env().to_raw();
+    let is_name = unsafe {
+        neon_runtime::tag::is_string(env, local) || neon_runtime::tag::is_symbol(env, local)
+    };
+
+    if is_name {
+        Ok(local)
+    } else {
+        cx.throw_type_error("Property key must be a string or symbol")
+    }
+}
+
+/// Defines `properties` on `object` with a single call to `napi_define_properties`.
+pub(crate) fn define<'a, 'b, C, K, I>(
+    cx: &mut C,
+    object: raw::Local,
+    properties: I,
+) -> NeonResult<()>
+where
+    C: Context<'a>,
+    K: PropertyKey,
+    I: IntoIterator<Item = (K, PropertyDescriptor<'b>)>,
+{
+    let env = cx.env();
+    let mut descriptors = Vec::new();
+
+    for (key, descriptor) in properties {
+        let name = key_to_name(cx, key)?;
+        let mut property = neon_runtime::property::Descriptor {
+            name,
+            ..Default::default()
+        };
+
+        property.attributes.writable = descriptor.writable;
+        property.attributes.enumerable = descriptor.enumerable;
+        property.attributes.configurable = descriptor.configurable;
+
+        match descriptor.kind {
+            Kind::Value(value) => property.value = value.to_raw(),
+            #[cfg(feature = "napi-5")]
+            Kind::Accessor(Accessor { getter, setter }) => {
+                // Each callback is passed its own function as `data`, since the engine
+                // may call it after the object is gone, e.g., through
+                // `Object.getOwnPropertyDescriptor(obj, key).get`. A descriptor has a
+                // single `data` pointer, so the setter is added by a second descriptor.
+                // The property is configurable until then.
+                if let Some(getter) = getter {
+                    let callback = GetterCallback(getter).into_c_callback();
+                    let mut getter_property = property;
+
+                    getter_property.getter = callback.static_callback;
+                    getter_property.data = callback.dynamic_callback;
+
+                    if setter.is_none() {
+                        descriptors.push(getter_property);
+                        continue;
+                    }
+
+                    getter_property.attributes.configurable = true;
+                    descriptors.push(getter_property);
+                }
+
+                if let Some(setter) = setter {
+                    let callback = SetterCallback(setter).into_c_callback();
+
+                    property.setter = callback.static_callback;
+                    property.data = callback.dynamic_callback;
+                }
+            }
+        }
+
+        descriptors.push(property);
+    }
+
+    if unsafe { neon_runtime::object::define_properties(env.to_raw(), object, &descriptors) } {
+        return Ok(());
+    }
+
+    // The engine throws if a property can't be redefined, but fails without an
+    // exception if the object is not extensible, e.g., a `JsBox`
+    if unsafe { neon_runtime::error::is_throwing(env.to_raw()) } {
+        Err(Throw)
+    } else {
+        cx.throw_type_error("Cannot define property, object is not extensible")
+    }
+}
+
+/// Calls the getter of an accessor property. The `Getter` is passed as the callback data.
+#[cfg(feature = "napi-5")]
+struct GetterCallback(Getter);
+
+/// Calls the setter of an accessor property. The `Setter` is passed as the callback data.
+#[cfg(feature = "napi-5")]
+struct SetterCallback(Setter);
+
+#[cfg(feature = "napi-5")]
+impl Callback<raw::Local> for GetterCallback {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            info.with_cx::<JsObject, _, _>(env, |cx| {
+                let getter: Getter = mem::transmute(info.data(env));
+
+                match convert_panics(env, || getter(cx)) {
+                    Ok(value) => value.to_raw(),
+                    // An exception is pending; see `FunctionCallback`
+                    Err(_) => std::ptr::null_mut(),
+                }
+            })
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        self.0 as *mut _
+    }
+}
+
+#[cfg(feature = "napi-5")]
+impl Callback<raw::Local> for SetterCallback {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            info.with_cx::<JsObject, _, _>(env, |cx| {
+                let setter: Setter = mem::transmute(info.data(env));
+                let _ = convert_panics(env, || setter(cx));
+
+                std::ptr::null_mut()
+            })
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        self.0 as *mut _
+    }
+}
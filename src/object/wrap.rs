@@ -0,0 +1,171 @@
+//! Wrapping Rust data in JavaScript objects and defining classes of wrapped objects.
+
+use std::any::{self, Any};
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use neon_runtime::raw;
+
+use crate::context::internal::Env;
+use crate::context::{Context, FinalizeContext};
+use crate::result::JsResult;
+use crate::types::boxed::Finalize;
+use crate::types::JsFunction;
+
+type BoxAny = Box<dyn Any + Send + 'static>;
+
+/// A Rust type exposed to JavaScript as a class.
+///
+/// `Class` is implemented with the [`#[neon::class]`](crate::class) attribute on an
+/// `impl` block. Instances of the class wrap a `RefCell` containing the Rust value,
+/// which is finalized when the JavaScript object is garbage collected.
+///
+/// ```
+/// # use neon::prelude::*;
+/// struct Person {
+///     name: String,
+/// }
+///
+/// impl Finalize for Person {}
+///
+/// #[neon::class]
+/// impl Person {
+///     #[neon(constructor)]
+///     fn new(mut cx: FunctionContext) -> NeonResult<Self> {
+///         let name = cx.argument::<JsString>(0)?.value(&mut cx);
+///
+///         Ok(Person { name })
+///     }
+///
+///     fn greet<'a>(&self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsString> {
+///         Ok(cx.string(format!("Hello, {}!", self.name)))
+///     }
+/// }
+///
+/// #[neon::main]
+/// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+///     let constructor = Person::constructor(&mut cx)?;
+///
+///     cx.export_value("Person", constructor)?;
+///
+///     Ok(())
+/// }
+/// ```
+pub trait Class: Finalize + Send + Sized + 'static {
+    /// Defines the class and returns its constructor.
+    ///
+    /// Each call defines a new, distinct class. A module should usually define a
+    /// class once and export the constructor.
+    fn constructor<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsFunction>;
+}
+
+/// The error returned by [`Object::unwrap`](crate::object::Object::unwrap) when an
+/// object does not wrap a value of type `T`.
+pub struct UnwrapError<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> UnwrapError<T> {
+    pub(crate) fn new() -> Self {
+        UnwrapError {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for UnwrapError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "UnwrapError")
+    }
+}
+
+impl<T> fmt::Display for UnwrapError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "object does not wrap a {}", any::type_name::<T>())
+    }
+}
+
+impl<T> Error for UnwrapError<T> {}
+
+/// Only its address is used, to identify values wrapped by this module. The address
+/// is distinct in every native module loaded in the process.
+static TAG: u8 = 0;
+
+/// A value wrapped by this module.
+///
+/// `napi_unwrap` returns the pointer an object was wrapped with by _any_ native
+/// module, e.g., another addon wrapping an object it was passed. The pointer may
+/// only be treated as a `Wrapped` if it starts with the address of `TAG`.
+#[repr(C)]
+struct Wrapped {
+    tag: usize,
+    value: BoxAny,
+}
+
+fn tag() -> usize {
+    &TAG as *const u8 as usize
+}
+
+/// Wraps `value` in `object`. Returns `false` if `object` is already wrapped.
+pub(crate) fn wrap<T: Finalize + Send + 'static>(env: Env, object: raw::Local, value: T) -> bool {
+    // This function will execute immediately before the object is garbage collected.
+    // It downcasts the `BoxAny` and calls `Finalize::finalize` on the wrapped value.
+    fn finalizer<U: Finalize + 'static>(env: raw::Env, data: Wrapped) {
+        let data = *data.value.downcast::<U>().unwrap();
+        let env = unsafe { std::mem::transmute(env) };
+
+        FinalizeContext::with(env, move |mut cx| data.finalize(&mut cx));
+    }
+
+    let value = Wrapped {
+        tag: tag(),
+        value: Box::new(value),
+    };
+
+    unsafe { neon_runtime::object::wrap(env.to_raw(), object, value, finalizer::<T>) }
+}
+
+/// Returns a pointer to the `BoxAny` wrapped in `object` if it was wrapped by this module
+fn unwrap_any(env: Env, object: raw::Local) -> Option<*const BoxAny> {
+    let ptr = unsafe { neon_runtime::object::unwrap::<Wrapped>(env.to_raw(), object) }?;
+
+    // The tag is read without assuming the pointer is aligned for a `Wrapped`,
+    // since it may have been wrapped by another module
+    if ptr.is_null() || unsafe { (ptr as *const usize).read_unaligned() } != tag() {
+        return None;
+    }
+
+    Some(unsafe { &(*ptr).value })
+}
+
+/// Returns a reference to the value of type `T` wrapped in `object`, or `None` if
+/// `object` is not wrapped, wraps a value of another type or was wrapped by another
+/// native module.
+///
+/// # Safety
+///
+/// The reference must not outlive `object`
+pub(crate) unsafe fn unwrap<'a, T: Send + 'static>(env: Env, object: raw::Local) -> Option<&'a T> {
+    unwrap_any(env, object).and_then(|v| (*v).downcast_ref::<T>())
+}
+
+/// Removes and returns the value of type `T` wrapped in `object`. Returns `None`,
+/// leaving `object` unchanged, if `object` is not wrapped, wraps a value of another
+/// type or was wrapped by another native module.
+///
+/// # Safety
+///
+/// No references returned by `unwrap` for `object` may be alive
+pub(crate) unsafe fn remove_wrap<T: Send + 'static>(env: Env, object: raw::Local) -> Option<T> {
+    let ptr = unwrap_any(env, object)?;
+
+    if !(*ptr).is::<T>() {
+        return None;
+    }
+
+    let wrapped = neon_runtime::object::remove_wrap::<Wrapped>(env.to_raw(), object)?;
+
+    // The type was checked above
+    Some(*wrapped.value.downcast::<T>().unwrap())
+}
@@ -15,18 +15,20 @@ pub use crate::event::{Channel, SendError};
 #[allow(deprecated)]
 pub use crate::event::{EventQueue, EventQueueError};
 pub use crate::handle::Handle;
-#[cfg(feature = "legacy-runtime")]
 pub use crate::object::Class;
 pub use crate::object::Object;
 pub use crate::register_module;
+#[cfg(feature = "napi-1")]
+pub use crate::result::IntoJsError;
 pub use crate::result::{JsResult, JsResultExt, NeonResult};
-#[cfg(feature = "legacy-runtime")]
 pub use crate::task::Task;
 pub use crate::types::{
     BinaryData, JsArray, JsArrayBuffer, JsBoolean, JsBuffer, JsError, JsFunction, JsNull, JsNumber,
     JsObject, JsString, JsUndefined, JsValue, Value,
 };
 #[cfg(feature = "napi-1")]
+pub use crate::types::{Deferred, JsPromise};
+#[cfg(feature = "napi-1")]
 pub use crate::{
     handle::Root,
     types::boxed::{Finalize, JsBox},
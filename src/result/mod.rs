@@ -35,7 +35,11 @@
 
 use crate::context::Context;
 use crate::handle::Handle;
+#[cfg(feature = "napi-1")]
+use crate::object::Object;
 use crate::types::Value;
+#[cfg(feature = "napi-1")]
+use crate::types::{ErrorKind, JsError, JsObject, JsString, JsSymbol, JsUndefined, JsValue};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 /// A [unit type][unit] indicating that the JavaScript thread is throwing an exception.
@@ -66,3 +70,377 @@ pub type JsResult<'b, T> = NeonResult<Handle<'b, T>>;
 pub trait JsResultExt<'a, V: Value> {
     fn or_throw<'b, C: Context<'b>>(self, cx: &mut C) -> JsResult<'a, V>;
 }
+
+/// Customizes the conversion of a Rust error into a JavaScript error, for use with
+/// [`ResultExt::or_throw_js`](ResultExt::or_throw_js).
+///
+/// By default, the conversion is the same as [`ResultExt::or_throw`](ResultExt::or_throw):
+/// the JavaScript error is a direct instance of `Error` with the
+/// [`Display`](std::fmt::Display) output of the Rust error as its message, and the chain of
+/// [`Error::source`](std::error::Error::source) errors is converted to a chain of
+/// `Error` objects linked by their `cause` property. Types may override the class
+/// and `code` of the error, or the whole conversion.
+///
+/// ```
+/// # #[cfg(feature = "napi-1")] {
+/// # use neon::prelude::*;
+/// use std::fmt;
+///
+/// use neon::result::{IntoJsError, ResultExt};
+/// use neon::types::ErrorKind;
+///
+/// #[derive(Debug)]
+/// struct OutOfStock(String);
+///
+/// impl fmt::Display for OutOfStock {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "{} is out of stock", self.0)
+///     }
+/// }
+///
+/// impl std::error::Error for OutOfStock {}
+///
+/// impl IntoJsError for OutOfStock {
+///     fn kind(&self) -> ErrorKind {
+///         ErrorKind::RangeError
+///     }
+///
+///     fn code(&self) -> Option<String> {
+///         Some("ERR_OUT_OF_STOCK".to_string())
+///     }
+/// }
+///
+/// fn reserve(item: &str) -> Result<u32, OutOfStock> {
+///     Err(OutOfStock(item.to_string()))
+/// }
+///
+/// fn reserve_item(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let item = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let id = reserve(&item).or_throw_js(&mut cx)?;
+///
+///     Ok(cx.number(id))
+/// }
+/// # }
+/// ```
+#[cfg(feature = "napi-1")]
+pub trait IntoJsError: std::error::Error {
+    /// The class of the JavaScript error. Defaults to `Error`.
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Error
+    }
+
+    /// The `code` property of the JavaScript error, e.g., `ERR_INVALID_ARG_VALUE`.
+    /// Defaults to no code.
+    fn code(&self) -> Option<String> {
+        None
+    }
+
+    /// Creates the JavaScript error. Errors in the source chain are converted to
+    /// plain `Error` objects, since their concrete types are unknown.
+    fn to_js_error<'a, C: Context<'a>>(&self, cx: &mut C) -> JsResult<'a, JsError> {
+        let mut builder = JsError::builder(self.to_string()).kind(self.kind());
+
+        if let Some(code) = self.code() {
+            builder = builder.code(code);
+        }
+
+        if let Some(source) = self.source() {
+            builder = builder.cause(error_to_js_error(cx, source, 1)?);
+        }
+
+        builder.build(cx)
+    }
+}
+
+/// Converts `err` and its sources to a chain of `Error` objects linked by `cause`.
+/// `depth` is the number of causes above `err`; sources deeper than
+/// `MAX_CAUSE_DEPTH` are ignored.
+#[cfg(feature = "napi-1")]
+fn error_to_js_error<'a, C: Context<'a>>(
+    cx: &mut C,
+    err: &dyn std::error::Error,
+    depth: usize,
+) -> JsResult<'a, JsError> {
+    let mut messages = vec![err.to_string()];
+    let mut source = err.source();
+
+    while let Some(err) = source {
+        if depth + messages.len() > MAX_CAUSE_DEPTH {
+            break;
+        }
+
+        messages.push(err.to_string());
+        source = err.source();
+    }
+
+    // Build the chain from the innermost cause outwards
+    let mut cause: Option<Handle<'a, JsError>> = None;
+
+    for msg in messages.into_iter().rev() {
+        let mut builder = JsError::builder(msg);
+
+        if let Some(cause) = cause {
+            builder = builder.cause(cause);
+        }
+
+        cause = Some(builder.build(cx)?);
+    }
+
+    Ok(cause.unwrap())
+}
+
+#[cfg(feature = "napi-1")]
+impl IntoJsError for std::io::Error {}
+
+#[cfg(feature = "napi-1")]
+impl IntoJsError for std::fmt::Error {}
+
+#[cfg(feature = "napi-1")]
+impl IntoJsError for std::str::Utf8Error {}
+
+#[cfg(feature = "napi-1")]
+impl IntoJsError for std::string::FromUtf8Error {}
+
+#[cfg(feature = "napi-1")]
+impl IntoJsError for std::num::ParseIntError {}
+
+#[cfg(feature = "napi-1")]
+impl IntoJsError for std::num::ParseFloatError {}
+
+#[cfg(feature = "napi-1")]
+impl IntoJsError for std::num::TryFromIntError {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::RangeError
+    }
+}
+
+/// Extension trait for converting Rust [`Result`](std::result::Result) values with
+/// a [`std::error::Error`](std::error::Error) into [`NeonResult`](NeonResult) values
+/// by throwing the converted error.
+///
+/// `ResultExt` is not part of the [prelude](crate::prelude), since its `or_throw`
+/// method would be ambiguous with [`JsResultExt::or_throw`](JsResultExt::or_throw) for
+/// errors such as [`DowncastError`](crate::handle::DowncastError). Import it from
+/// `neon::result`.
+///
+/// ```
+/// # #[cfg(feature = "napi-1")] {
+/// # use neon::prelude::*;
+/// use neon::result::ResultExt;
+///
+/// fn read_file(mut cx: FunctionContext) -> JsResult<JsString> {
+///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let contents = std::fs::read_to_string(path).or_throw(&mut cx)?;
+///
+///     Ok(cx.string(contents))
+/// }
+/// # }
+/// ```
+#[cfg(feature = "napi-1")]
+pub trait ResultExt<T, E> {
+    /// Throws an `Error` with the [`Display`](std::fmt::Display) output of the Rust
+    /// error as its message and the chain of [`source`](std::error::Error::source)
+    /// errors as its `cause`.
+    fn or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T>;
+
+    /// Throws the error created by [`IntoJsError::to_js_error`](IntoJsError::to_js_error),
+    /// e.g., to customize the class or `code` of the JavaScript error.
+    fn or_throw_js<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T>
+    where
+        E: IntoJsError;
+}
+
+#[cfg(feature = "napi-1")]
+impl<T, E: std::error::Error> ResultExt<T, E> for Result<T, E> {
+    fn or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                let err = error_to_js_error(cx, &e, 0)?;
+
+                cx.throw(err)
+            }
+        }
+    }
+
+    fn or_throw_js<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T>
+    where
+        E: IntoJsError,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                let err = e.to_js_error(cx)?;
+
+                cx.throw(err)
+            }
+        }
+    }
+}
+
+/// Causes nested more deeply than this are ignored, e.g., if an error is its own cause
+#[cfg(feature = "napi-1")]
+const MAX_CAUSE_DEPTH: usize = 32;
+
+/// A JavaScript exception converted to a Rust error, e.g., after it was caught by
+/// [`Context::try_catch`](crate::context::Context::try_catch).
+///
+/// Any value may be thrown in JavaScript. The `name`, `message`, `stack`, `code`
+/// and `cause` properties are read from objects if present. Other values only have
+/// a message, the result of converting them to a string.
+///
+/// `CaughtError` owns its data and may outlive the context and be sent to other
+/// threads. It implements [`std::error::Error`](std::error::Error), with the `cause`
+/// as its [`source`](std::error::Error::source).
+///
+/// ```
+/// # #[cfg(all(feature = "napi-1", feature = "try-catch-api"))] {
+/// # use neon::prelude::*;
+/// use neon::result::CaughtError;
+///
+/// fn call_or_describe(mut cx: FunctionContext) -> JsResult<JsString> {
+///     let f = cx.argument::<JsFunction>(0)?;
+///     let result = cx.try_catch(|cx| {
+///         let null = cx.null();
+///         f.call(cx, null, Vec::<Handle<JsValue>>::new())
+///     });
+///
+///     match result {
+///         Ok(_) => Ok(cx.string("ok")),
+///         Err(exception) => {
+///             let err = CaughtError::new(&mut cx, exception)?;
+///
+///             Ok(cx.string(err.to_string()))
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(feature = "napi-1")]
+#[derive(Clone, Debug)]
+pub struct CaughtError {
+    name: Option<String>,
+    message: String,
+    stack: Option<String>,
+    code: Option<String>,
+    cause: Option<Box<CaughtError>>,
+}
+
+#[cfg(feature = "napi-1")]
+impl CaughtError {
+    /// Reads the properties of a thrown `value`. Fails if reading a property or
+    /// converting the value to a string throws.
+    pub fn new<'a, C: Context<'a>, V: Value>(cx: &mut C, value: Handle<V>) -> NeonResult<Self> {
+        Self::with_depth(cx, value.upcast(), 0)
+    }
+
+    fn with_depth<'a, C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<JsValue>,
+        depth: usize,
+    ) -> NeonResult<Self> {
+        let obj = match value.downcast::<JsObject, _>(cx) {
+            Ok(obj) => obj,
+            Err(_) => {
+                return Ok(CaughtError {
+                    name: None,
+                    message: display_value(cx, value)?,
+                    stack: None,
+                    code: None,
+                    cause: None,
+                })
+            }
+        };
+
+        let name = string_property(cx, obj, "name")?;
+        let message = match string_property(cx, obj, "message")? {
+            Some(message) => message,
+            None => display_value(cx, value)?,
+        };
+        let stack = string_property(cx, obj, "stack")?;
+        let code = string_property(cx, obj, "code")?;
+        let cause: Handle<JsValue> = obj.get(cx, "cause")?;
+        let cause = if cause.is_a::<JsUndefined, _>(cx) || depth >= MAX_CAUSE_DEPTH {
+            None
+        } else {
+            Some(Box::new(Self::with_depth(cx, cause, depth + 1)?))
+        };
+
+        Ok(CaughtError {
+            name,
+            message,
+            stack,
+            code,
+            cause,
+        })
+    }
+
+    /// The `name` property, e.g., `TypeError`
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The `message` property, or the thrown value converted to a string
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The `stack` property
+    pub fn stack(&self) -> Option<&str> {
+        self.stack.as_deref()
+    }
+
+    /// The `code` property, e.g., `ERR_INVALID_ARG_TYPE`
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// The `cause` property
+    pub fn cause(&self) -> Option<&CaughtError> {
+        self.cause.as_deref()
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl Display for CaughtError {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        match &self.name {
+            Some(name) if !name.is_empty() => write!(fmt, "{}: {}", name, self.message),
+            _ => fmt.write_str(&self.message),
+        }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl std::error::Error for CaughtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.cause
+            .as_deref()
+            .map(|cause| cause as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// Reads a property if it's a string
+#[cfg(feature = "napi-1")]
+fn string_property<'a, C: Context<'a>>(
+    cx: &mut C,
+    obj: Handle<JsObject>,
+    key: &str,
+) -> NeonResult<Option<String>> {
+    let value: Handle<JsValue> = obj.get(cx, key)?;
+
+    Ok(value.downcast::<JsString, _>(cx).ok().map(|s| s.value(cx)))
+}
+
+/// Converts a value to a string as with `String(value)`, which unlike `value + ""`
+/// doesn't throw for symbols
+#[cfg(feature = "napi-1")]
+fn display_value<'a, C: Context<'a>>(cx: &mut C, value: Handle<JsValue>) -> NeonResult<String> {
+    if let Ok(symbol) = value.downcast::<JsSymbol, _>(cx) {
+        let description = symbol.description(cx)?.unwrap_or_default();
+
+        return Ok(format!("Symbol({})", description));
+    }
+
+    Ok(value.to_string(cx)?.value(cx))
+}
@@ -1,14 +1,37 @@
 //! Utilities for scheduling tasks to be executed by the Node.js runtime
 
+#[cfg(feature = "napi-1")]
+use std::cell::Cell;
 use std::marker::{Send, Sized};
+#[cfg(feature = "legacy-runtime")]
 use std::os::raw::c_void;
+#[cfg(feature = "napi-1")]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "napi-1")]
+use std::rc::Rc;
 
+#[cfg(feature = "napi-1")]
+use neon_runtime::async_work;
+use neon_runtime::raw;
+
+#[cfg(feature = "napi-1")]
+use crate::context::internal::Env;
+#[cfg(feature = "napi-1")]
+use crate::context::Context;
 use crate::context::TaskContext;
+#[cfg(feature = "napi-1")]
+use crate::handle::Root;
 use crate::handle::{Handle, Managed};
+#[cfg(feature = "napi-1")]
+use crate::object::Object;
 use crate::result::JsResult;
+#[cfg(feature = "napi-1")]
+use crate::types::error::convert_panics;
+#[cfg(feature = "napi-1")]
+use crate::types::{Deferred, JsPromise};
+#[cfg(feature = "napi-1")]
+use crate::types::{JsError, JsValue};
 use crate::types::{JsFunction, Value};
-use neon_runtime;
-use neon_runtime::raw;
 
 /// A Rust task that can be executed in the background on the Node thread pool.
 pub trait Task: Send + Sized + 'static {
@@ -38,6 +61,7 @@ pub trait Task: Send + Sized + 'static {
     /// ```js
     /// function callback(err, value) {}
     /// ```
+    #[cfg(feature = "legacy-runtime")]
     fn schedule(self, callback: Handle<JsFunction>) {
         let boxed_self = Box::new(self);
         let self_raw = Box::into_raw(boxed_self);
@@ -51,8 +75,43 @@ pub trait Task: Send + Sized + 'static {
             );
         }
     }
+
+    /// Schedule a task to be executed on a background thread.
+    ///
+    /// `callback` should have the following signature:
+    ///
+    /// ```js
+    /// function callback(err, value) {}
+    /// ```
+    ///
+    /// If `perform` or `complete` panics, `callback` is called with an `Error`.
+    /// The returned [`ScheduledTask`](ScheduledTask) may be used to cancel the
+    /// task before it starts executing.
+    #[cfg(feature = "napi-1")]
+    fn schedule<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+        callback: Handle<JsFunction>,
+    ) -> ScheduledTask {
+        let env = cx.env();
+        let is_complete = Rc::new(Cell::new(false));
+        let data = (callback.root(cx), Rc::clone(&is_complete));
+
+        let work = unsafe {
+            async_work::schedule(
+                env.to_raw(),
+                self,
+                perform_task::<Self>,
+                complete_task::<Self>,
+                data,
+            )
+        };
+
+        ScheduledTask { work, is_complete }
+    }
 }
 
+#[cfg(feature = "legacy-runtime")]
 unsafe extern "C" fn perform_task<T: Task>(task: *mut c_void) -> *mut c_void {
     let task: Box<T> = Box::from_raw(task.cast());
     let result = task.perform();
@@ -60,6 +119,7 @@ unsafe extern "C" fn perform_task<T: Task>(task: *mut c_void) -> *mut c_void {
     Box::into_raw(Box::new(result)).cast()
 }
 
+#[cfg(feature = "legacy-runtime")]
 unsafe extern "C" fn complete_task<T: Task>(
     task: *mut c_void,
     result: *mut c_void,
@@ -73,3 +133,203 @@ unsafe extern "C" fn complete_task<T: Task>(
         }
     })
 }
+
+/// A handle to a [`Task`](Task) that has been scheduled on the libuv thread pool.
+///
+/// A `ScheduledTask` may only be used on the JavaScript thread that scheduled the task.
+#[cfg(feature = "napi-1")]
+pub struct ScheduledTask {
+    work: raw::AsyncWork,
+    is_complete: Rc<Cell<bool>>,
+}
+
+#[cfg(feature = "napi-1")]
+impl ScheduledTask {
+    /// Attempts to cancel the task. Returns `true` if the task was cancelled before it
+    /// started executing; the callback will be called with an `Error` and
+    /// [`Task::complete`](Task::complete) is not called. Returns `false` if the task
+    /// is executing or has already completed.
+    pub fn cancel<'a, C: Context<'a>>(self, cx: &mut C) -> bool {
+        // The async work is deleted after the task completes
+        if self.is_complete.get() {
+            return false;
+        }
+
+        unsafe { async_work::cancel(cx.env().to_raw(), self.work) }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+type TaskOutput<T> = (T, Result<<T as Task>::Output, <T as Task>::Error>);
+
+#[cfg(feature = "napi-1")]
+fn perform_task<T: Task>(task: T) -> TaskOutput<T> {
+    let result = task.perform();
+    (task, result)
+}
+
+#[cfg(feature = "napi-1")]
+fn complete_task<T: Task>(
+    env: raw::Env,
+    output: async_work::Output<TaskOutput<T>>,
+    (callback, is_complete): (Root<JsFunction>, Rc<Cell<bool>>),
+) {
+    let env: Env = unsafe { std::mem::transmute(env) };
+
+    is_complete.set(true);
+
+    // Note: It is sufficient to use `TaskContext`'s `InheritedHandleScope` because
+    // N-API creates a `HandleScope` before calling the complete callback.
+    TaskContext::with_context(env, move |mut cx| {
+        let completion = match output {
+            async_work::Output::Value((task, result)) => {
+                // The `TaskContext` is moved into `complete`, so the value is passed
+                // out as a raw handle. It remains valid in the enclosing scope.
+                convert_panics(
+                    env,
+                    AssertUnwindSafe(move || {
+                        TaskContext::with_context(env, move |cx| {
+                            task.complete(cx, result).map(|v| v.to_raw())
+                        })
+                    }),
+                )
+            }
+            async_work::Output::Panic(panic) => {
+                convert_panics(env, AssertUnwindSafe(move || panic::resume_unwind(panic)))
+            }
+            async_work::Output::Cancelled => {
+                JsError::error(&mut cx, "Task was cancelled").and_then(|err| cx.throw(err))
+            }
+        };
+
+        let mut exception: raw::Local = unsafe { std::mem::zeroed() };
+        let args: Vec<Handle<JsValue>> =
+            if unsafe { neon_runtime::error::catch_error(env.to_raw(), &mut exception) } {
+                vec![JsValue::new_internal(exception)]
+            } else if let Ok(value) = completion {
+                vec![cx.null().upcast(), JsValue::new_internal(value)]
+            } else {
+                vec![unexpected_throw(&mut cx)]
+            };
+
+        let callback = callback.into_inner(&mut cx);
+        let this = cx.null();
+
+        // An exception thrown by the callback is left pending and reported by
+        // Node as an uncaught exception
+        let _ = callback.call::<_, _, JsValue, _>(&mut cx, this, args);
+    });
+}
+
+/// Builds the `Error` reported when `complete` returns `Err(Throw)` without a
+/// pending exception, e.g., by propagating the result of an inner `try_catch`.
+/// Panicking instead would abort the process from the async work callback.
+#[cfg(feature = "napi-1")]
+fn unexpected_throw<'a>(cx: &mut TaskContext<'a>) -> Handle<'a, JsValue> {
+    let msg = "complete: closure returned Err(Throw) without a pending exception";
+
+    match JsError::error(cx, msg) {
+        Ok(err) => err.upcast(),
+        Err(_) => cx.undefined().upcast(),
+    }
+}
+
+/// Builds a closure to execute on the libuv thread pool, created by
+/// [`Context::task`](crate::context::Context::task).
+#[cfg(feature = "napi-1")]
+pub struct TaskBuilder<'cx, C, E> {
+    cx: &'cx mut C,
+    execute: E,
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a: 'cx, 'cx, C, O, E> TaskBuilder<'cx, C, E>
+where
+    C: Context<'a>,
+    O: Send + 'static,
+    E: FnOnce() -> O + Send + 'static,
+{
+    /// Creates a builder for executing `execute` on the libuv thread pool
+    pub fn new(cx: &'cx mut C, execute: E) -> Self {
+        Self { cx, execute }
+    }
+
+    /// Schedules the task and returns a promise of its result. Once the task has
+    /// executed, `complete` is called on the JavaScript thread to convert its output
+    /// to a JavaScript value that resolves the promise.
+    ///
+    /// The promise is rejected if `complete` throws, or with an `Error` if the task
+    /// or `complete` panics.
+    pub fn promise<V, F>(self, complete: F) -> Handle<'a, JsPromise>
+    where
+        V: Value,
+        F: FnOnce(TaskContext, O) -> JsResult<V> + 'static,
+    {
+        let env = self.cx.env();
+        let (deferred, promise) = JsPromise::new(self.cx);
+
+        unsafe {
+            async_work::schedule(
+                env.to_raw(),
+                self.execute,
+                execute_closure::<O, E>,
+                complete_promise::<O, V, F>,
+                (deferred, complete),
+            );
+        }
+
+        promise
+    }
+}
+
+#[cfg(feature = "napi-1")]
+fn execute_closure<O, E: FnOnce() -> O>(execute: E) -> O {
+    execute()
+}
+
+#[cfg(feature = "napi-1")]
+fn complete_promise<O, V, F>(
+    env: raw::Env,
+    output: async_work::Output<O>,
+    (deferred, complete): (Deferred, F),
+) where
+    V: Value,
+    F: FnOnce(TaskContext, O) -> JsResult<V>,
+{
+    let env: Env = unsafe { std::mem::transmute(env) };
+
+    // Note: It is sufficient to use `TaskContext`'s `InheritedHandleScope` because
+    // N-API creates a `HandleScope` before calling the complete callback.
+    TaskContext::with_context(env, move |mut cx| {
+        let completion = match output {
+            async_work::Output::Value(output) => {
+                // As in `complete_task`, the value is passed out as a raw handle
+                convert_panics(
+                    env,
+                    AssertUnwindSafe(move || {
+                        TaskContext::with_context(env, move |cx| {
+                            complete(cx, output).map(|v| v.to_raw())
+                        })
+                    }),
+                )
+            }
+            async_work::Output::Panic(panic) => {
+                convert_panics(env, AssertUnwindSafe(move || panic::resume_unwind(panic)))
+            }
+            async_work::Output::Cancelled => {
+                JsError::error(&mut cx, "Task was cancelled").and_then(|err| cx.throw(err))
+            }
+        };
+
+        let mut exception: raw::Local = unsafe { std::mem::zeroed() };
+
+        if unsafe { neon_runtime::error::catch_error(env.to_raw(), &mut exception) } {
+            deferred.reject(&mut cx, JsValue::new_internal(exception));
+        } else if let Ok(value) = completion {
+            deferred.resolve(&mut cx, JsValue::new_internal(value));
+        } else {
+            let err = unexpected_throw(&mut cx);
+            deferred.reject(&mut cx, err);
+        }
+    });
+}
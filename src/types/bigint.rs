@@ -0,0 +1,274 @@
+//! Types for working with [`JsBigInt`].
+
+use super::{Value, ValueInternal};
+use crate::context::internal::Env;
+use crate::context::Context;
+use crate::handle::{Handle, Managed};
+use crate::result::{JsResult, Throw};
+use neon_runtime;
+use neon_runtime::raw;
+use std::error::Error;
+use std::fmt;
+
+/// A JavaScript BigInt value, an integer of arbitrary precision.
+///
+/// BigInt values are stored by the JavaScript engine as a sign and a magnitude made
+/// of little-endian 64-bit words. Conversions to fixed width Rust integers report
+/// whether they were lossless; values that do not fit produce a [`RangeError`] that
+/// still holds the truncated value.
+///
+/// ## Example
+///
+/// ```
+/// # #[cfg(feature = "napi-6")] {
+/// # use neon::prelude::*;
+/// use neon::types::JsBigInt;
+///
+/// fn add_one(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+///     let n = cx.argument::<JsBigInt>(0)?;
+///
+///     let n = match n.to_i128(&mut cx) {
+///         Ok(n) => n,
+///         Err(err) => return cx.throw_range_error(err.to_string()),
+///     };
+///
+///     Ok(JsBigInt::from_i128(&mut cx, n + 1))
+/// }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JsBigInt(raw::Local);
+
+/// The sign of a [`JsBigInt`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sign {
+    Positive,
+    Negative,
+}
+
+impl Sign {
+    fn from_negative(negative: bool) -> Self {
+        if negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        }
+    }
+}
+
+/// The error returned when a [`JsBigInt`] does not fit in the requested Rust
+/// integer type.
+///
+/// The error holds the value truncated to the width of `T`, matching the
+/// semantics of `BigInt.asIntN` and `BigInt.asUintN`.
+#[derive(Debug)]
+pub struct RangeError<T>(T);
+
+impl<T> RangeError<T> {
+    /// Get a reference to the lossy, truncated value
+    pub fn get_ref(&self) -> &T {
+        &self.0
+    }
+
+    /// Consume the error and return the lossy, truncated value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for RangeError<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "BigInt does not fit in {}; lossy value: {}",
+            std::any::type_name::<T>(),
+            self.0,
+        )
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> Error for RangeError<T> {}
+
+fn result<T>(value: T, lossless: bool) -> Result<T, RangeError<T>> {
+    if lossless {
+        Ok(value)
+    } else {
+        Err(RangeError(value))
+    }
+}
+
+impl JsBigInt {
+    /// Creates a BigInt from an `i64`
+    pub fn from_i64<'a, C: Context<'a>>(cx: &mut C, n: i64) -> Handle<'a, JsBigInt> {
+        let local = unsafe { neon_runtime::bigint::new_i64(cx.env().to_raw(), n) };
+        Handle::new_internal(JsBigInt(local))
+    }
+
+    /// Creates a BigInt from a `u64`
+    pub fn from_u64<'a, C: Context<'a>>(cx: &mut C, n: u64) -> Handle<'a, JsBigInt> {
+        let local = unsafe { neon_runtime::bigint::new_u64(cx.env().to_raw(), n) };
+        Handle::new_internal(JsBigInt(local))
+    }
+
+    /// Creates a BigInt from an `i128`
+    pub fn from_i128<'a, C: Context<'a>>(cx: &mut C, n: i128) -> Handle<'a, JsBigInt> {
+        let sign = if n < 0 {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+
+        Self::from_u128_words(cx, sign, n.unsigned_abs())
+    }
+
+    /// Creates a BigInt from a `u128`
+    pub fn from_u128<'a, C: Context<'a>>(cx: &mut C, n: u128) -> Handle<'a, JsBigInt> {
+        Self::from_u128_words(cx, Sign::Positive, n)
+    }
+
+    fn from_u128_words<'a, C: Context<'a>>(
+        cx: &mut C,
+        sign: Sign,
+        magnitude: u128,
+    ) -> Handle<'a, JsBigInt> {
+        let words = [magnitude as u64, (magnitude >> 64) as u64];
+        let env = cx.env().to_raw();
+        let mut local: raw::Local = std::ptr::null_mut();
+        let ok = unsafe {
+            neon_runtime::bigint::new_words(&mut local, env, sign == Sign::Negative, &words)
+        };
+
+        // Two words can never exceed the maximum size of a BigInt
+        assert!(ok, "failed to create BigInt from 128-bit value");
+
+        Handle::new_internal(JsBigInt(local))
+    }
+
+    /// Creates a BigInt from a sign and a magnitude of little-endian 64-bit words.
+    ///
+    /// Throws a `RangeError` if the magnitude is larger than the JavaScript engine
+    /// supports.
+    pub fn from_words<'a, C: Context<'a>>(
+        cx: &mut C,
+        sign: Sign,
+        words: &[u64],
+    ) -> JsResult<'a, JsBigInt> {
+        let env = cx.env().to_raw();
+        let mut local: raw::Local = std::ptr::null_mut();
+        let ok = unsafe {
+            neon_runtime::bigint::new_words(&mut local, env, sign == Sign::Negative, words)
+        };
+
+        if ok {
+            Ok(Handle::new_internal(JsBigInt(local)))
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Gets the value of the BigInt as an `i64`. Returns a [`RangeError`] holding the
+    /// truncated value if the BigInt does not fit.
+    pub fn to_i64<'a, C: Context<'a>>(self, cx: &mut C) -> Result<i64, RangeError<i64>> {
+        let (n, lossless) =
+            unsafe { neon_runtime::bigint::get_i64(cx.env().to_raw(), self.to_raw()) };
+
+        result(n, lossless)
+    }
+
+    /// Gets the value of the BigInt as a `u64`. Returns a [`RangeError`] holding the
+    /// truncated value if the BigInt is negative or does not fit.
+    pub fn to_u64<'a, C: Context<'a>>(self, cx: &mut C) -> Result<u64, RangeError<u64>> {
+        let (n, lossless) =
+            unsafe { neon_runtime::bigint::get_u64(cx.env().to_raw(), self.to_raw()) };
+
+        result(n, lossless)
+    }
+
+    /// Gets the value of the BigInt as an `i128`. Returns a [`RangeError`] holding the
+    /// truncated value if the BigInt does not fit.
+    pub fn to_i128<'a, C: Context<'a>>(self, cx: &mut C) -> Result<i128, RangeError<i128>> {
+        let (sign, magnitude, len) = self.to_u128_words(cx);
+        let lossless = len <= 2
+            && match sign {
+                Sign::Positive => magnitude <= i128::MAX as u128,
+                Sign::Negative => magnitude <= i128::MIN.unsigned_abs(),
+            };
+
+        let n = match sign {
+            Sign::Positive => magnitude as i128,
+            Sign::Negative => (magnitude as i128).wrapping_neg(),
+        };
+
+        result(n, lossless)
+    }
+
+    /// Gets the value of the BigInt as a `u128`. Returns a [`RangeError`] holding the
+    /// truncated value if the BigInt is negative or does not fit.
+    pub fn to_u128<'a, C: Context<'a>>(self, cx: &mut C) -> Result<u128, RangeError<u128>> {
+        let (sign, magnitude, len) = self.to_u128_words(cx);
+        let lossless = len <= 2 && (sign == Sign::Positive || magnitude == 0);
+        let n = match sign {
+            Sign::Positive => magnitude,
+            Sign::Negative => magnitude.wrapping_neg(),
+        };
+
+        result(n, lossless)
+    }
+
+    // Reads the lowest two words of the magnitude; also returns the total number of words
+    fn to_u128_words<'a, C: Context<'a>>(self, cx: &mut C) -> (Sign, u128, usize) {
+        let mut words = [0u64; 2];
+        let (sign, len) = self.read_words(cx, &mut words);
+        let magnitude = (words[0] as u128) | ((words[1] as u128) << 64);
+
+        (sign, magnitude, len)
+    }
+
+    /// Gets the sign and magnitude of the BigInt, as little-endian 64-bit words
+    pub fn to_words<'a, C: Context<'a>>(self, cx: &mut C) -> (Sign, Vec<u64>) {
+        let mut words = vec![0; self.words_len(cx)];
+        let (sign, _) = self.read_words(cx, &mut words);
+
+        (sign, words)
+    }
+
+    /// Fills `words` with the magnitude of the BigInt, as little-endian 64-bit words.
+    /// If `words` is too short, only the least significant words are written.
+    ///
+    /// Returns the sign and the total number of words in the magnitude, which may be
+    /// larger than `words.len()`.
+    pub fn read_words<'a, C: Context<'a>>(self, cx: &mut C, words: &mut [u64]) -> (Sign, usize) {
+        let (negative, len) =
+            unsafe { neon_runtime::bigint::get_words(cx.env().to_raw(), self.to_raw(), words) };
+
+        (Sign::from_negative(negative), len)
+    }
+
+    /// Gets the number of 64-bit words needed to represent the magnitude of the BigInt
+    pub fn words_len<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        unsafe { neon_runtime::bigint::word_count(cx.env().to_raw(), self.to_raw()) }
+    }
+}
+
+impl Value for JsBigInt {}
+
+impl Managed for JsBigInt {
+    fn to_raw(self) -> raw::Local {
+        self.0
+    }
+
+    fn from_raw(_: Env, h: raw::Local) -> Self {
+        JsBigInt(h)
+    }
+}
+
+impl ValueInternal for JsBigInt {
+    fn name() -> String {
+        "bigint".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_bigint(env.to_raw(), other.to_raw()) }
+    }
+}
@@ -9,9 +9,17 @@ use crate::handle::Handle;
 use crate::handle::Managed;
 use crate::result::JsResult;
 use crate::types::internal::ValueInternal;
+#[cfg(feature = "napi-1")]
+use crate::types::internal::{DataViewElementInternal, TypedArrayElementInternal};
 use crate::types::{build, Object, Value};
 use neon_runtime;
+#[cfg(feature = "napi-1")]
+use neon_runtime::dataview::DataViewInfo;
 use neon_runtime::raw;
+#[cfg(feature = "napi-1")]
+use neon_runtime::typedarray::TypedArrayInfo;
+#[cfg(feature = "napi-1")]
+use std::convert::{TryFrom, TryInto};
 use std::marker::PhantomData;
 use std::mem::{self, MaybeUninit};
 use std::os::raw::c_void;
@@ -323,3 +331,542 @@ impl<'a> BorrowMut for &'a mut JsArrayBuffer {
         unsafe { RefMut::new(guard, data.assume_init()) }
     }
 }
+
+/// A JavaScript [`Uint8ClampedArray`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Uint8ClampedArray)
+/// element. It is a distinct type from `u8` so that `JsTypedArray<u8>` and
+/// `JsTypedArray<Uint8Clamped>` can be told apart.
+#[cfg(feature = "napi-1")]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uint8Clamped(pub u8);
+
+#[cfg(feature = "napi-1")]
+impl BinaryViewType for Uint8Clamped {}
+
+/// The trait for element types of a [`JsTypedArray`].
+#[cfg(feature = "napi-1")]
+pub trait TypedArrayElement: BinaryViewType + Copy + TypedArrayElementInternal + 'static {}
+
+#[cfg(feature = "napi-1")]
+macro_rules! impl_typed_array_element {
+    ($(#[$attr:meta])* $t:ty => $kind:ident) => {
+        $(#[$attr])*
+        impl TypedArrayElementInternal for $t {
+            fn kind() -> raw::TypedArrayType {
+                raw::TypedArrayType::$kind
+            }
+        }
+
+        $(#[$attr])*
+        impl TypedArrayElement for $t {}
+    };
+}
+
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(i8 => Int8Array);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(u8 => Uint8Array);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(Uint8Clamped => Uint8ClampedArray);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(i16 => Int16Array);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(u16 => Uint16Array);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(i32 => Int32Array);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(u32 => Uint32Array);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(f32 => Float32Array);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(f64 => Float64Array);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(#[cfg(feature = "napi-6")] i64 => BigInt64Array);
+#[cfg(feature = "napi-1")]
+impl_typed_array_element!(#[cfg(feature = "napi-6")] u64 => BigUint64Array);
+
+/// A JavaScript [TypedArray](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypedArray)
+/// view, such as a `Uint8Array` or a `Float64Array`, with elements of type `T`.
+///
+/// A TypedArray may be a view over only part of its underlying `ArrayBuffer`.
+/// Borrowing a `JsTypedArray` produces [`BinaryData`] covering exactly the
+/// elements in the view, starting at its byte offset.
+///
+/// ## Example
+///
+/// ```
+/// # #[cfg(feature = "napi-1")] {
+/// # use neon::prelude::*;
+/// use neon::types::JsTypedArray;
+///
+/// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let array = cx.argument::<JsTypedArray<f32>>(0)?;
+///     let sum: f32 = cx.borrow(&array, |data| data.as_slice::<f32>().iter().sum());
+///
+///     Ok(cx.number(sum))
+/// }
+/// # }
+/// ```
+#[cfg(feature = "napi-1")]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JsTypedArray<T: TypedArrayElement> {
+    local: raw::Local,
+    _element: PhantomData<T>,
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement> JsTypedArray<T> {
+    /// Constructs a new TypedArray of `len` zero-filled elements, backed by a
+    /// new `ArrayBuffer`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, len: usize) -> JsResult<'a, Self> {
+        let size = match len
+            .checked_mul(mem::size_of::<T>())
+            .and_then(|size| u32::try_from(size).ok())
+        {
+            Some(size) => size,
+            None => return cx.throw_range_error("TypedArray length is too large"),
+        };
+
+        let buf = JsArrayBuffer::new(cx, size)?;
+
+        Self::from_region(cx, buf, 0, len)
+    }
+
+    /// Constructs a new TypedArray containing a copy of `values`.
+    pub fn from_slice<'a, C: Context<'a>>(cx: &mut C, values: &[T]) -> JsResult<'a, Self> {
+        let mut array = Self::new(cx, values.len())?;
+
+        cx.borrow_mut(&mut array, |data| {
+            data.as_mut_slice::<T>().copy_from_slice(values);
+        });
+
+        Ok(array)
+    }
+
+    /// Constructs a new TypedArray view of `len` elements over `buf`, starting
+    /// at `offset` bytes.
+    ///
+    /// Throws a `RangeError` if `offset` is not a multiple of the element size
+    /// or if the view does not fit in the buffer.
+    pub fn from_region<'a, C: Context<'a>>(
+        cx: &mut C,
+        buf: Handle<JsArrayBuffer>,
+        offset: usize,
+        len: usize,
+    ) -> JsResult<'a, Self> {
+        let env = cx.env();
+
+        build(env, |out| unsafe {
+            neon_runtime::typedarray::new(out, env.to_raw(), T::kind(), buf.to_raw(), offset, len)
+        })
+    }
+
+    fn info<'a, C: Context<'a>>(self, cx: &mut C) -> TypedArrayInfo {
+        unsafe { neon_runtime::typedarray::info(cx.env().to_raw(), self.to_raw()) }
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        self.info(cx).length
+    }
+
+    /// Returns `true` if the view has no elements.
+    pub fn is_empty<'a, C: Context<'a>>(self, cx: &mut C) -> bool {
+        self.len(cx) == 0
+    }
+
+    /// Returns the offset of the view in its `ArrayBuffer`, in bytes.
+    pub fn offset<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        self.info(cx).offset
+    }
+
+    /// Returns the size of the view, in bytes.
+    pub fn size<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        self.len(cx) * mem::size_of::<T>()
+    }
+
+    /// Returns the `ArrayBuffer` backing the view.
+    pub fn buffer<'a, C: Context<'a>>(self, cx: &mut C) -> Handle<'a, JsArrayBuffer> {
+        Handle::new_internal(JsArrayBuffer(self.info(cx).buf))
+    }
+
+    /// Copies the elements of the view into a new `Vec`.
+    pub fn to_vec<'a, C: Context<'a>>(self, cx: &mut C) -> Vec<T> {
+        typed_array_data::<T>(cx.env(), self.to_raw())
+            .as_slice::<T>()
+            .to_vec()
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement> Managed for JsTypedArray<T> {
+    fn to_raw(self) -> raw::Local {
+        self.local
+    }
+
+    fn from_raw(_env: Env, h: raw::Local) -> Self {
+        JsTypedArray {
+            local: h,
+            _element: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement> ValueInternal for JsTypedArray<T> {
+    fn name() -> String {
+        format!("{:?}", T::kind())
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe {
+            neon_runtime::tag::is_typedarray(env.to_raw(), other.to_raw())
+                && neon_runtime::typedarray::info(env.to_raw(), other.to_raw()).kind == T::kind()
+        }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement> Value for JsTypedArray<T> {}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement> Object for JsTypedArray<T> {}
+
+#[cfg(feature = "napi-1")]
+fn typed_array_data<'a, T: TypedArrayElement>(env: Env, array: raw::Local) -> BinaryData<'a> {
+    let info = unsafe { neon_runtime::typedarray::info(env.to_raw(), array) };
+
+    BinaryData {
+        base: info.data,
+        size: info.length * mem::size_of::<T>(),
+        phantom: PhantomData,
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a, T: TypedArrayElement> Borrow for &'a JsTypedArray<T> {
+    type Target = BinaryData<'a>;
+
+    fn try_borrow<'b>(self, guard: &'b Lock<'b>) -> Result<Ref<'b, Self::Target>, LoanError> {
+        let data = typed_array_data::<T>(guard.env, self.to_raw());
+
+        unsafe { Ref::new(guard, data) }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a, T: TypedArrayElement> Borrow for &'a mut JsTypedArray<T> {
+    type Target = BinaryData<'a>;
+
+    fn try_borrow<'b>(self, guard: &'b Lock<'b>) -> Result<Ref<'b, Self::Target>, LoanError> {
+        (self as &'a JsTypedArray<T>).try_borrow(guard)
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a, T: TypedArrayElement> BorrowMut for &'a mut JsTypedArray<T> {
+    fn try_borrow_mut<'b>(
+        self,
+        guard: &'b Lock<'b>,
+    ) -> Result<RefMut<'b, Self::Target>, LoanError> {
+        let data = typed_array_data::<T>(guard.env, self.to_raw());
+
+        unsafe { RefMut::new(guard, data) }
+    }
+}
+
+/// The trait for values that can be read from and written to a [`JsDataView`],
+/// in either byte order.
+#[cfg(feature = "napi-1")]
+pub trait DataViewElement: Copy + DataViewElementInternal {}
+
+#[cfg(feature = "napi-1")]
+macro_rules! impl_data_view_element {
+    ($($t:ty),*) => {
+        $(
+            impl DataViewElementInternal for $t {
+                fn read(bytes: &[u8], little_endian: bool) -> Option<Self> {
+                    let bytes = bytes.get(..mem::size_of::<$t>())?.try_into().ok()?;
+
+                    Some(if little_endian {
+                        <$t>::from_le_bytes(bytes)
+                    } else {
+                        <$t>::from_be_bytes(bytes)
+                    })
+                }
+
+                fn write(self, bytes: &mut [u8], little_endian: bool) -> bool {
+                    let bytes = match bytes.get_mut(..mem::size_of::<$t>()) {
+                        Some(bytes) => bytes,
+                        None => return false,
+                    };
+
+                    if little_endian {
+                        bytes.copy_from_slice(&self.to_le_bytes());
+                    } else {
+                        bytes.copy_from_slice(&self.to_be_bytes());
+                    }
+
+                    true
+                }
+            }
+
+            impl DataViewElement for $t {}
+        )*
+    };
+}
+
+#[cfg(feature = "napi-1")]
+impl_data_view_element!(i8, u8, i16, u16, i32, u32, i64, u64, f32, f64);
+
+/// The JS [`DataView`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/DataView) type.
+///
+/// A DataView is a view over a region of an `ArrayBuffer`. Borrowing a `JsDataView`
+/// produces [`DataViewData`], which reads and writes values of any
+/// [`DataViewElement`] type at byte offsets within the view, in either byte order.
+///
+/// ## Example
+///
+/// ```
+/// # #[cfg(feature = "napi-1")] {
+/// # use neon::prelude::*;
+/// use neon::types::JsDataView;
+///
+/// // Reads a big-endian length prefix followed by a little-endian `f64`
+/// fn read_header(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let view = cx.argument::<JsDataView>(0)?;
+///     let value = cx.borrow(&view, |data| {
+///         let len = data.get_be::<u16>(0)?;
+///         let value = data.get_le::<f64>(2)?;
+///
+///         Some(value * f64::from(len))
+///     });
+///
+///     match value {
+///         Some(value) => Ok(cx.number(value)),
+///         None => cx.throw_range_error("header is truncated"),
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(feature = "napi-1")]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JsDataView(raw::Local);
+
+#[cfg(feature = "napi-1")]
+impl JsDataView {
+    /// Constructs a new DataView of `size` zero-filled bytes, backed by a new
+    /// `ArrayBuffer`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, size: u32) -> JsResult<'a, JsDataView> {
+        let buf = JsArrayBuffer::new(cx, size)?;
+
+        JsDataView::from_region(cx, buf, 0, size as usize)
+    }
+
+    /// Constructs a new DataView over the whole of `buf`.
+    pub fn from_buffer<'a, C: Context<'a>>(
+        cx: &mut C,
+        buf: Handle<JsArrayBuffer>,
+    ) -> JsResult<'a, JsDataView> {
+        let size = cx.borrow(&buf, |data| data.len());
+
+        JsDataView::from_region(cx, buf, 0, size)
+    }
+
+    /// Constructs a new DataView of `size` bytes over `buf`, starting at `offset`
+    /// bytes.
+    ///
+    /// Throws a `RangeError` if the view does not fit in the buffer.
+    pub fn from_region<'a, C: Context<'a>>(
+        cx: &mut C,
+        buf: Handle<JsArrayBuffer>,
+        offset: usize,
+        size: usize,
+    ) -> JsResult<'a, JsDataView> {
+        let env = cx.env();
+
+        build(env, |out| unsafe {
+            neon_runtime::dataview::new(out, env.to_raw(), buf.to_raw(), offset, size)
+        })
+    }
+
+    fn info<'a, C: Context<'a>>(self, cx: &mut C) -> DataViewInfo {
+        unsafe { neon_runtime::dataview::info(cx.env().to_raw(), self.to_raw()) }
+    }
+
+    /// Returns the size of the view, in bytes.
+    pub fn size<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        self.info(cx).size
+    }
+
+    /// Returns the offset of the view in its `ArrayBuffer`, in bytes.
+    pub fn offset<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        self.info(cx).offset
+    }
+
+    /// Returns the `ArrayBuffer` backing the view.
+    pub fn buffer<'a, C: Context<'a>>(self, cx: &mut C) -> Handle<'a, JsArrayBuffer> {
+        Handle::new_internal(JsArrayBuffer(self.info(cx).buf))
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl Managed for JsDataView {
+    fn to_raw(self) -> raw::Local {
+        self.0
+    }
+
+    fn from_raw(_env: Env, h: raw::Local) -> Self {
+        JsDataView(h)
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl ValueInternal for JsDataView {
+    fn name() -> String {
+        "DataView".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_dataview(env.to_raw(), other.to_raw()) }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl Value for JsDataView {}
+
+#[cfg(feature = "napi-1")]
+impl Object for JsDataView {}
+
+/// A reference to the bytes viewed by a `DataView`, which can be accessed via the
+/// `Borrow` and `BorrowMut` traits. Offsets are relative to the start of the view.
+#[cfg(feature = "napi-1")]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DataViewData<'a> {
+    base: *mut c_void,
+    size: usize,
+    phantom: PhantomData<&'a ()>,
+}
+
+#[cfg(feature = "napi-1")]
+unsafe impl<'a> Pointer for DataViewData<'a> {
+    unsafe fn as_ptr(&self) -> *const c_void {
+        self.base
+    }
+
+    unsafe fn as_mut(&mut self) -> *mut c_void {
+        self.base
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a> DataViewData<'a> {
+    /// Produces the bytes of the view.
+    pub fn as_slice(&self) -> &[u8] {
+        if self.size == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.base.cast(), self.size) }
+        }
+    }
+
+    /// Produces the bytes of the view, mutably.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        if self.size == 0 {
+            &mut []
+        } else {
+            unsafe { slice::from_raw_parts_mut(self.base.cast(), self.size) }
+        }
+    }
+
+    /// Produces the length of the view, in bytes.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the view is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get<T: DataViewElement>(&self, offset: usize, little_endian: bool) -> Option<T> {
+        T::read(self.as_slice().get(offset..)?, little_endian)
+    }
+
+    fn set<T: DataViewElement>(&mut self, offset: usize, value: T, little_endian: bool) -> bool {
+        match self.as_mut_slice().get_mut(offset..) {
+            Some(bytes) => value.write(bytes, little_endian),
+            None => false,
+        }
+    }
+
+    /// Reads a little-endian value at `offset` bytes. Returns `None` if the value
+    /// does not fit in the view.
+    pub fn get_le<T: DataViewElement>(&self, offset: usize) -> Option<T> {
+        self.get(offset, true)
+    }
+
+    /// Reads a big-endian value at `offset` bytes. Returns `None` if the value
+    /// does not fit in the view.
+    pub fn get_be<T: DataViewElement>(&self, offset: usize) -> Option<T> {
+        self.get(offset, false)
+    }
+
+    /// Writes a little-endian value at `offset` bytes. Returns `false`, leaving
+    /// the view unchanged, if the value does not fit in the view.
+    pub fn set_le<T: DataViewElement>(&mut self, offset: usize, value: T) -> bool {
+        self.set(offset, value, true)
+    }
+
+    /// Writes a big-endian value at `offset` bytes. Returns `false`, leaving
+    /// the view unchanged, if the value does not fit in the view.
+    pub fn set_be<T: DataViewElement>(&mut self, offset: usize, value: T) -> bool {
+        self.set(offset, value, false)
+    }
+}
+
+#[cfg(feature = "napi-1")]
+fn data_view_data<'a>(env: Env, view: raw::Local) -> DataViewData<'a> {
+    let info = unsafe { neon_runtime::dataview::info(env.to_raw(), view) };
+
+    DataViewData {
+        base: info.data,
+        size: info.size,
+        phantom: PhantomData,
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a> Borrow for &'a JsDataView {
+    type Target = DataViewData<'a>;
+
+    fn try_borrow<'b>(self, guard: &'b Lock<'b>) -> Result<Ref<'b, Self::Target>, LoanError> {
+        let data = data_view_data(guard.env, self.to_raw());
+
+        unsafe { Ref::new(guard, data) }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a> Borrow for &'a mut JsDataView {
+    type Target = DataViewData<'a>;
+
+    fn try_borrow<'b>(self, guard: &'b Lock<'b>) -> Result<Ref<'b, Self::Target>, LoanError> {
+        (self as &'a JsDataView).try_borrow(guard)
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a> BorrowMut for &'a mut JsDataView {
+    fn try_borrow_mut<'b>(
+        self,
+        guard: &'b Lock<'b>,
+    ) -> Result<RefMut<'b, Self::Target>, LoanError> {
+        let data = data_view_data(guard.env, self.to_raw());
+
+        unsafe { RefMut::new(guard, data) }
+    }
+}
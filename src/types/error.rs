@@ -7,9 +7,15 @@ use neon_runtime::raw;
 
 use crate::context::internal::Env;
 use crate::context::Context;
+#[cfg(feature = "napi-1")]
+use crate::object::PropertyDescriptor;
+#[cfg(feature = "napi-1")]
+use crate::result::JsResult;
 use crate::result::{NeonResult, Throw};
 use crate::types::internal::ValueInternal;
 use crate::types::utf8::Utf8;
+#[cfg(feature = "napi-1")]
+use crate::types::JsValue;
 use crate::types::{build, Handle, Managed, Object, Value};
 
 /// A JS `Error` object.
@@ -79,6 +85,166 @@ impl JsError {
     }
 }
 
+#[cfg(feature = "napi-1")]
+impl JsError {
+    /// Starts building an [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error)
+    /// with `msg`. See [`ErrorBuilder`].
+    pub fn builder<'a, S: Into<String>>(msg: S) -> ErrorBuilder<'a> {
+        ErrorBuilder::new(msg)
+    }
+}
+
+/// The class of an error created by an [`ErrorBuilder`].
+#[cfg(feature = "napi-1")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A direct instance of `Error`
+    Error,
+    /// An instance of `TypeError`
+    TypeError,
+    /// An instance of `RangeError`
+    RangeError,
+}
+
+/// Builds an error with a `code`, a `cause`, a custom `name` or additional properties.
+///
+/// ```
+/// # #[cfg(feature = "napi-1")] {
+/// # use neon::prelude::*;
+/// use neon::types::ErrorKind;
+///
+/// fn parse_port(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let arg = cx.argument::<JsString>(0)?;
+///     let port = arg.value(&mut cx);
+///
+///     match port.parse::<u16>() {
+///         Ok(port) => Ok(cx.number(port)),
+///         Err(err) => JsError::builder(err.to_string())
+///             .kind(ErrorKind::RangeError)
+///             .code("ERR_INVALID_PORT")
+///             .property("input", arg)
+///             .throw(&mut cx),
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(feature = "napi-1")]
+#[derive(Clone)]
+pub struct ErrorBuilder<'a> {
+    kind: ErrorKind,
+    message: String,
+    code: Option<String>,
+    name: Option<String>,
+    cause: Option<Handle<'a, JsValue>>,
+    properties: Vec<(String, Handle<'a, JsValue>)>,
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a> ErrorBuilder<'a> {
+    /// Starts building a direct instance of `Error` with `msg`
+    pub fn new<S: Into<String>>(msg: S) -> Self {
+        ErrorBuilder {
+            kind: ErrorKind::Error,
+            message: msg.into(),
+            code: None,
+            name: None,
+            cause: None,
+            properties: Vec::new(),
+        }
+    }
+
+    /// Sets the class of the error
+    pub fn kind(mut self, kind: ErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the `code` property, e.g., `ERR_INVALID_ARG_TYPE`
+    pub fn code<S: Into<String>>(mut self, code: S) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Overrides the `name` property inherited from the error class. As with
+    /// `Error.prototype.name`, the property is not enumerable.
+    ///
+    /// The `stack` property is captured when the error is created and keeps the
+    /// name of the class.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the `cause` property to the error or value that led to this error. As
+    /// with the `cause` option of the `Error` constructor, the property is not
+    /// enumerable.
+    pub fn cause<V: Value>(mut self, cause: Handle<'a, V>) -> Self {
+        self.cause = Some(cause.upcast());
+        self
+    }
+
+    /// Sets an additional property on the error. May be called multiple times.
+    pub fn property<K: Into<String>, V: Value>(mut self, key: K, value: Handle<'a, V>) -> Self {
+        self.properties.push((key.into(), value.upcast()));
+        self
+    }
+
+    /// Creates the error
+    pub fn build<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsError> {
+        let env = cx.env().to_raw();
+        let msg = cx.string(&self.message);
+        let code = match &self.code {
+            Some(code) => cx.string(code).to_raw(),
+            None => std::ptr::null_mut(),
+        };
+
+        let err: Handle<JsError> = build(cx.env(), |out| unsafe {
+            match self.kind {
+                ErrorKind::Error => {
+                    neon_runtime::error::new_error_with_code(env, out, code, msg.to_raw())
+                }
+                ErrorKind::TypeError => {
+                    neon_runtime::error::new_type_error_with_code(env, out, code, msg.to_raw())
+                }
+                ErrorKind::RangeError => {
+                    neon_runtime::error::new_range_error_with_code(env, out, code, msg.to_raw())
+                }
+            }
+            true
+        })?;
+
+        if let Some(name) = &self.name {
+            let name = cx.string(name);
+            let descriptor = PropertyDescriptor::value(name)
+                .writable(true)
+                .configurable(true);
+
+            err.define_property(cx, "name", descriptor)?;
+        }
+
+        if let Some(cause) = self.cause {
+            let descriptor = PropertyDescriptor::value(cause)
+                .writable(true)
+                .configurable(true);
+
+            err.define_property(cx, "cause", descriptor)?;
+        }
+
+        for (key, value) in self.properties {
+            err.set(cx, key.as_str(), value)?;
+        }
+
+        Ok(err)
+    }
+
+    /// Creates and throws the error
+    pub fn throw<C: Context<'a>, T>(self, cx: &mut C) -> NeonResult<T> {
+        let err = self.build(cx)?;
+
+        cx.throw(err)
+    }
+}
+
 pub(crate) fn convert_panics<T, F: UnwindSafe + FnOnce() -> NeonResult<T>>(
     env: Env,
     f: F,
@@ -3,12 +3,16 @@ use crate::context::internal::Env;
 use crate::context::{CallbackInfo, FunctionContext};
 use crate::result::JsResult;
 use crate::types::error::convert_panics;
+#[cfg(feature = "napi-5")]
+use crate::types::JsValue;
 use crate::types::{Handle, JsObject, Managed};
 use neon_runtime;
 use neon_runtime::call::CCallback;
 use neon_runtime::raw;
 use std::mem;
 use std::os::raw::c_void;
+#[cfg(feature = "napi-5")]
+use std::panic::AssertUnwindSafe;
 
 pub trait ValueInternal: Managed + 'static {
     fn name() -> String;
@@ -79,6 +83,48 @@ impl<T: Value> Callback<raw::Local> for FunctionCallback<T> {
     }
 }
 
+/// A boxed Rust closure used as the callback of a JavaScript function. The closure
+/// is owned by the function and freed by `ClosureCallback::drop_ptr` when the function
+/// is garbage collected.
+#[cfg(feature = "napi-5")]
+pub(crate) struct ClosureCallback(pub Box<dyn Fn(FunctionContext) -> JsResult<JsValue>>);
+
+#[cfg(feature = "napi-5")]
+impl ClosureCallback {
+    /// Drops a closure previously converted with `into_ptr`
+    pub(crate) fn drop_ptr(ptr: *mut c_void) {
+        unsafe {
+            drop(Box::from_raw(
+                ptr as *mut Box<dyn Fn(FunctionContext) -> JsResult<JsValue>>,
+            ));
+        }
+    }
+}
+
+#[cfg(feature = "napi-5")]
+impl Callback<raw::Local> for ClosureCallback {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            info.with_cx::<JsObject, _, _>(env, |cx| {
+                let data = neon_runtime::fun::get_dynamic_callback(env.to_raw(), info.data(env));
+                let closure = &*(data as *const Box<dyn Fn(FunctionContext) -> JsResult<JsValue>>);
+
+                // The closure is not required to be `UnwindSafe`; like `fn` callbacks, a
+                // panic is converted to an exception and the closure may be called again.
+                if let Ok(value) = convert_panics(env, AssertUnwindSafe(|| closure(cx))) {
+                    value.to_raw()
+                } else {
+                    std::ptr::null_mut()
+                }
+            })
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        Box::into_raw(Box::new(self.0)) as *mut _
+    }
+}
+
 /// A dynamically computed callback that can be passed through C to the engine.
 /// This type makes it possible to export a dynamically computed Rust function
 /// as a pair of 1) a raw pointer to the dynamically computed function, and 2)
@@ -113,3 +159,21 @@ pub(crate) trait Callback<T: Clone + Copy + Sized>: Sized {
         }
     }
 }
+
+/// Maps a [`TypedArrayElement`](crate::types::TypedArrayElement) to the N-API kind of
+/// the TypedArray holding it.
+#[cfg(feature = "napi-1")]
+pub trait TypedArrayElementInternal {
+    fn kind() -> raw::TypedArrayType;
+}
+
+/// Encodes and decodes a [`DataViewElement`](crate::types::DataViewElement) as bytes
+/// in either byte order.
+#[cfg(feature = "napi-1")]
+pub trait DataViewElementInternal: Sized {
+    /// Reads a value from the start of `bytes`; `None` if `bytes` is too short
+    fn read(bytes: &[u8], little_endian: bool) -> Option<Self>;
+
+    /// Writes a value to the start of `bytes`; `false` if `bytes` is too short
+    fn write(self, bytes: &mut [u8], little_endian: bool) -> bool;
+}
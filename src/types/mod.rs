@@ -61,27 +61,37 @@
 //!   types all implement the [`Object`](crate::object::Object) trait, which allows
 //!   getting and setting properties.
 //!   - **Standard object types:** [`JsFunction`](JsFunction), [`JsArray`](JsArray),
-//!     [`JsDate`](JsDate), and [`JsError`](JsError).
-//!   - **Typed arrays:** [`JsBuffer`](JsBuffer) and [`JsArrayBuffer`](JsArrayBuffer).
+//!     [`JsDate`](JsDate), [`JsError`](JsError), and [`JsPromise`](JsPromise).
+//!   - **Typed arrays:** [`JsBuffer`](JsBuffer), [`JsArrayBuffer`](JsArrayBuffer),
+//!     [`JsTypedArray`](JsTypedArray), and [`JsDataView`](JsDataView).
 //!   - **Custom types:** [`JsBox`](JsBox), a special Neon type that allows the creation
 //!     of custom objects that own Rust data structures.
 //! - **Primitive types:** These are the built-in JavaScript datatypes that are not
-//!   object types: [`JsNumber`](JsNumber), [`JsBoolean`](JsBoolean),
-//!   [`JsString`](JsString), [`JsNull`](JsNull), and [`JsUndefined`](JsUndefined).
+//!   object types: [`JsNumber`](JsNumber), [`JsBigInt`](JsBigInt), [`JsBoolean`](JsBoolean),
+//!   [`JsString`](JsString), [`JsSymbol`](JsSymbol), [`JsNull`](JsNull), and
+//!   [`JsUndefined`](JsUndefined).
 //!
 //! [types]: https://raw.githubusercontent.com/neon-bindings/neon/main/doc/types.jpg
 //! [unknown]: https://mariusschulz.com/blog/the-unknown-type-in-typescript#the-unknown-type
 
+#[cfg(feature = "napi-6")]
+pub mod bigint;
 pub(crate) mod binary;
 #[cfg(feature = "napi-1")]
 pub(crate) mod boxed;
 #[cfg(feature = "napi-5")]
 pub(crate) mod date;
 pub(crate) mod error;
+#[cfg(feature = "napi-1")]
+pub(crate) mod promise;
+#[cfg(feature = "napi-1")]
+pub(crate) mod symbol;
 
 pub(crate) mod internal;
 pub(crate) mod utf8;
 
+#[cfg(feature = "napi-5")]
+use self::internal::ClosureCallback;
 use self::internal::{FunctionCallback, ValueInternal};
 use self::utf8::Utf8;
 use crate::context::internal::Env;
@@ -99,12 +109,24 @@ use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::os::raw::c_void;
 
+#[cfg(feature = "napi-6")]
+pub use self::bigint::JsBigInt;
 pub use self::binary::{BinaryData, BinaryViewType, JsArrayBuffer, JsBuffer};
 #[cfg(feature = "napi-1")]
+pub use self::binary::{
+    DataViewData, DataViewElement, JsDataView, JsTypedArray, TypedArrayElement, Uint8Clamped,
+};
+#[cfg(feature = "napi-1")]
 pub use self::boxed::JsBox;
 #[cfg(feature = "napi-5")]
 pub use self::date::{DateError, DateErrorKind, JsDate};
 pub use self::error::JsError;
+#[cfg(feature = "napi-1")]
+pub use self::error::{ErrorBuilder, ErrorKind};
+#[cfg(feature = "napi-1")]
+pub use self::promise::{Deferred, JsPromise};
+#[cfg(feature = "napi-1")]
+pub use self::symbol::JsSymbol;
 
 pub(crate) fn build<'a, T: Managed, F: FnOnce(&mut raw::Local) -> bool>(
     env: Env,
@@ -134,6 +156,8 @@ impl<T: Object> SuperType<T> for JsObject {
 
 /// The trait shared by all JavaScript values.
 pub trait Value: ValueInternal {
+    /// Converts the value to a string, as with JavaScript's `String(value)`. Throws
+    /// if the conversion throws, e.g., for a symbol or from a custom `toString` method.
     fn to_string<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsString> {
         let env = cx.env();
         build(env, |out| unsafe {
@@ -141,6 +165,47 @@ pub trait Value: ValueInternal {
         })
     }
 
+    #[cfg(feature = "napi-1")]
+    /// Converts the value to a number, as with JavaScript's unary `+` operator. Throws
+    /// if the conversion throws, e.g., for a BigInt or from a custom `valueOf` method.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Accepts anything number-like, e.g., `"42"` or `new Number(42)`
+    /// fn port(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     cx.argument::<JsValue>(0)?.to_number(&mut cx)
+    /// }
+    /// ```
+    fn to_number<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsNumber> {
+        let env = cx.env();
+        build(env, |out| unsafe {
+            neon_runtime::convert::to_number(out, env.to_raw(), self.to_raw())
+        })
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Converts the value to a boolean, as with JavaScript's `Boolean(value)`. Unlike
+    /// the other conversions, this never throws.
+    fn to_boolean<'a, C: Context<'a>>(self, cx: &mut C) -> Handle<'a, JsBoolean> {
+        let env = cx.env();
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            neon_runtime::convert::to_boolean(&mut local, env.to_raw(), self.to_raw());
+            Handle::new_internal(JsBoolean(local))
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Converts the value to an object, as with JavaScript's `Object(value)`, wrapping
+    /// primitives in their wrapper objects. Throws a `TypeError` for `null` and
+    /// `undefined`.
+    fn to_object<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsObject> {
+        let env = cx.env();
+        build(env, |out| unsafe {
+            neon_runtime::convert::to_object(out, env.to_raw(), self.to_raw())
+        })
+    }
+
     fn as_value<'a, C: Context<'a>>(self, _: &mut C) -> Handle<'a, JsValue> {
         JsValue::new_internal(self.to_raw())
     }
@@ -446,10 +511,106 @@ impl JsString {
         }
     }
 
+    #[cfg(feature = "napi-1")]
+    /// Returns the length of the string in UTF-16 code units, as with the JavaScript
+    /// `length` property. This is also the length of the string in Latin-1.
+    pub fn utf16_len<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        let env = cx.env().to_raw();
+
+        unsafe { neon_runtime::string::utf16_len(env, self.to_raw()) }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Returns the UTF-16 code units of the string. Unlike [`value`](JsString::value),
+    /// unpaired surrogates are preserved, and indices match JavaScript string indices.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn char_code_at(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let s = cx.argument::<JsString>(0)?.to_utf16(&mut cx);
+    ///     let i = cx.argument::<JsNumber>(1)?.value_u32_or_throw(&mut cx)? as usize;
+    ///
+    ///     match s.get(i) {
+    ///         Some(&unit) => Ok(cx.number(unit)),
+    ///         None => cx.throw_range_error("index out of range"),
+    ///     }
+    /// }
+    /// ```
+    pub fn to_utf16<'a, C: Context<'a>>(self, cx: &mut C) -> Vec<u16> {
+        // Leave room for the null terminator written by the engine
+        let mut buffer = vec![0; self.utf16_len(cx) + 1];
+        let len = self.read_utf16(cx, &mut buffer);
+
+        buffer.truncate(len);
+        buffer
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Copies the UTF-16 code units of the string into `buffer` without allocating and
+    /// returns the number of code units copied.
+    ///
+    /// The engine always writes a null terminator after the copied code units, so at
+    /// most `buffer.len() - 1` code units are copied. To read the whole string, the
+    /// buffer should be longer than [`utf16_len`](JsString::utf16_len).
+    pub fn read_utf16<'a, C: Context<'a>>(self, cx: &mut C, buffer: &mut [u16]) -> usize {
+        let env = cx.env().to_raw();
+
+        unsafe { neon_runtime::string::data_utf16(env, buffer, self.to_raw()) }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Returns the string as Latin-1 (ISO-8859-1) bytes. Characters outside of Latin-1
+    /// are truncated to their lowest byte.
+    pub fn to_latin1<'a, C: Context<'a>>(self, cx: &mut C) -> Vec<u8> {
+        let mut buffer = vec![0; self.utf16_len(cx) + 1];
+        let len = self.read_latin1(cx, &mut buffer);
+
+        buffer.truncate(len);
+        buffer
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Copies the string as Latin-1 bytes into `buffer` without allocating and returns
+    /// the number of bytes copied. As with [`read_utf16`](JsString::read_utf16), at most
+    /// `buffer.len() - 1` bytes are copied.
+    pub fn read_latin1<'a, C: Context<'a>>(self, cx: &mut C, buffer: &mut [u8]) -> usize {
+        let env = cx.env().to_raw();
+
+        unsafe { neon_runtime::string::data_latin1(env, buffer, self.to_raw()) }
+    }
+
     pub fn new<'a, C: Context<'a>, S: AsRef<str>>(cx: &mut C, val: S) -> Handle<'a, JsString> {
         JsString::try_new(cx, val).unwrap()
     }
 
+    #[cfg(feature = "napi-1")]
+    /// Creates a string from UTF-16 code units, which may include unpaired surrogates.
+    /// Fails if the string is longer than the engine supports.
+    pub fn from_utf16<'a, C: Context<'a>>(cx: &mut C, units: &[u16]) -> StringResult<'a> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            if neon_runtime::string::new_utf16(&mut local, cx.env().to_raw(), units) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(units.len()))
+            }
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a string from Latin-1 (ISO-8859-1) bytes, where each byte is a character.
+    /// Fails if the string is longer than the engine supports.
+    pub fn from_latin1<'a, C: Context<'a>>(cx: &mut C, bytes: &[u8]) -> StringResult<'a> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            if neon_runtime::string::new_latin1(&mut local, cx.env().to_raw(), bytes) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(bytes.len()))
+            }
+        }
+    }
+
     pub fn try_new<'a, C: Context<'a>, S: AsRef<str>>(cx: &mut C, val: S) -> StringResult<'a> {
         let val = val.as_ref();
         match JsString::new_internal(cx.env(), val) {
@@ -504,6 +665,129 @@ impl JsNumber {
         let env = cx.env().to_raw();
         unsafe { neon_runtime::primitive::number_value(env, self.to_raw()) }
     }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a number from an `i32` without converting through `f64`
+    pub fn from_i32<'a, C: Context<'a>>(cx: &mut C, n: i32) -> Handle<'a, JsNumber> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            neon_runtime::primitive::number_i32(&mut local, cx.env().to_raw(), n);
+            Handle::new_internal(JsNumber(local))
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a number from a `u32` without converting through `f64`
+    pub fn from_u32<'a, C: Context<'a>>(cx: &mut C, n: u32) -> Handle<'a, JsNumber> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            neon_runtime::primitive::number_u32(&mut local, cx.env().to_raw(), n);
+            Handle::new_internal(JsNumber(local))
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a number from an `i64`. Values outside of the range of safe integers,
+    /// `Number.MIN_SAFE_INTEGER..=Number.MAX_SAFE_INTEGER`, lose precision.
+    pub fn from_i64<'a, C: Context<'a>>(cx: &mut C, n: i64) -> Handle<'a, JsNumber> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            neon_runtime::primitive::number_i64(&mut local, cx.env().to_raw(), n);
+            Handle::new_internal(JsNumber(local))
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value as an `i32`, as with the JavaScript expression `n | 0`. Fractions
+    /// are truncated, values out of range wrap around and non-finite values are `0`.
+    pub fn value_i32<'a, C: Context<'a>>(self, cx: &mut C) -> i32 {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::primitive::number_value_i32(env, self.to_raw()) }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value as a `u32`, as with the JavaScript expression `n >>> 0`. Fractions
+    /// are truncated, values out of range wrap around and non-finite values are `0`.
+    pub fn value_u32<'a, C: Context<'a>>(self, cx: &mut C) -> u32 {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::primitive::number_value_u32(env, self.to_raw()) }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value as an `i64`. Fractions are truncated and non-finite values are `0`.
+    pub fn value_i64<'a, C: Context<'a>>(self, cx: &mut C) -> i64 {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::primitive::number_value_i64(env, self.to_raw()) }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value as an `i32`, throwing a `RangeError` if it is not an integer in
+    /// the range of `i32`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn negate(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let n = cx.argument::<JsNumber>(0)?.value_i32_or_throw(&mut cx)?;
+    ///
+    ///     match n.checked_neg() {
+    ///         Some(n) => Ok(cx.number(n)),
+    ///         None => cx.throw_range_error("cannot negate the minimum i32"),
+    ///     }
+    /// }
+    /// ```
+    pub fn value_i32_or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<i32> {
+        let n = self.integer_or_throw(cx, i32::MIN.into(), i32::MAX.into())?;
+        Ok(n as i32)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value as a `u32`, throwing a `RangeError` if it is not an integer in
+    /// the range of `u32`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn get_element(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let array = cx.argument::<JsArray>(0)?;
+    ///     let index = cx.argument::<JsNumber>(1)?.value_u32_or_throw(&mut cx)?;
+    ///
+    ///     array.get(&mut cx, index)
+    /// }
+    /// ```
+    pub fn value_u32_or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<u32> {
+        let n = self.integer_or_throw(cx, 0, u32::MAX.into())?;
+        Ok(n as u32)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value as an `i64`, throwing a `RangeError` if it is not an integer in
+    /// the range of `i64`. Integers outside of the range of safe integers are accepted,
+    /// but may have lost precision when they were created.
+    pub fn value_i64_or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<i64> {
+        let n = self.integer_or_throw(cx, i64::MIN.into(), i64::MAX.into())?;
+        Ok(n as i64)
+    }
+
+    // Checks that the value is an integer in `min..=max`
+    #[cfg(feature = "napi-1")]
+    fn integer_or_throw<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+        min: i128,
+        max: i128,
+    ) -> NeonResult<f64> {
+        let n = self.value(cx);
+
+        // Comparing with the exclusive upper bound is exact, even where `max` isn't
+        // representable as an `f64`, e.g., `i64::MAX`
+        if n.trunc() == n && n >= min as f64 && n < (max + 1) as f64 {
+            Ok(n)
+        } else {
+            cx.throw_range_error(format!(
+                "Expected an integer between {} and {}, found {}",
+                min, max, n
+            ))
+        }
+    }
 }
 
 impl Value for JsNumber {}
@@ -714,6 +998,41 @@ impl JsFunction {
             }
         })
     }
+
+    /// Creates a function from a Rust closure, which may capture state.
+    ///
+    /// The closure is owned by the JavaScript function and dropped when the
+    /// function is garbage collected.
+    ///
+    /// ```
+    /// # #[cfg(feature = "napi-5")] {
+    /// # use neon::prelude::*;
+    /// fn make_greeter(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    ///     let greeting = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     JsFunction::from_closure(&mut cx, move |mut cx| {
+    ///         let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///         Ok(cx.string(format!("{}, {}!", greeting, name)))
+    ///     })
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "napi-5")]
+    pub fn from_closure<'a, C, F, V>(cx: &mut C, f: F) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        F: Fn(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+    {
+        let f: Box<dyn Fn(FunctionContext) -> JsResult<JsValue>> =
+            Box::new(move |cx| f(cx).map(|v| v.upcast()));
+        let env = cx.env();
+
+        build(env, |out| unsafe {
+            let callback = ClosureCallback(f).into_c_callback();
+            neon_runtime::fun::new_owned(out, env.to_raw(), callback, ClosureCallback::drop_ptr)
+        })
+    }
 }
 
 impl<CL: Object> JsFunction<CL> {
@@ -0,0 +1,310 @@
+use neon_runtime::raw;
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+use neon_runtime::tsfn::CallMode;
+#[cfg(feature = "napi-6")]
+use neon_runtime::tsfn::ThreadsafeFunction;
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+use std::marker::PhantomData;
+#[cfg(feature = "napi-6")]
+use std::sync::Arc;
+
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+use crate::context::internal::ContextInternal;
+use crate::context::internal::Env;
+use crate::context::Context;
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+use crate::context::TaskContext;
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+use crate::event::{Channel, ChannelTask, SendError};
+use crate::handle::{Handle, Managed};
+#[cfg(feature = "napi-6")]
+use crate::lifecycle::{DropData, InstanceData};
+use crate::object::Object;
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+use crate::result::{JsResult, NeonResult};
+use crate::types::internal::ValueInternal;
+use crate::types::Value;
+
+/// The JavaScript [`Promise`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise) type.
+///
+/// A `JsPromise` is created together with a [`Deferred`](Deferred) handle, which
+/// is used to settle (_resolve_ or _reject_) the promise. The `Deferred` may be
+/// sent to another thread and settled through a [`Channel`](crate::event::Channel),
+/// making it possible to return a promise from a Neon function and complete it
+/// asynchronously.
+///
+/// ## Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn fibonacci(_: f64) -> f64 { todo!() }
+/// fn async_fibonacci(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+///     let channel = cx.channel();
+///     let (deferred, promise) = cx.promise();
+///
+///     std::thread::spawn(move || {
+///         let result = fibonacci(n);
+///
+///         // Settle the promise on the JavaScript thread
+///         deferred.settle_with(&channel, move |mut cx| Ok(cx.number(result)));
+///     });
+///
+///     Ok(promise)
+/// }
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JsPromise(raw::Local);
+
+impl JsPromise {
+    /// Creates a new `Promise` and a [`Deferred`](Deferred) handle for settling it.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> (Deferred, Handle<'a, JsPromise>) {
+        let (deferred, promise) = unsafe { neon_runtime::promise::create(cx.env().to_raw()) };
+        let deferred = Deferred {
+            internal: Some(NapiDeferred(deferred)),
+            #[cfg(feature = "napi-6")]
+            drop_queue: InstanceData::drop_queue(cx),
+        };
+
+        (deferred, Handle::new_internal(JsPromise(promise)))
+    }
+}
+
+impl Value for JsPromise {}
+
+impl Managed for JsPromise {
+    fn to_raw(self) -> raw::Local {
+        self.0
+    }
+
+    fn from_raw(_: Env, h: raw::Local) -> Self {
+        JsPromise(h)
+    }
+}
+
+impl ValueInternal for JsPromise {
+    fn name() -> String {
+        "Promise".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_promise(env.to_raw(), other.to_raw()) }
+    }
+}
+
+impl Object for JsPromise {}
+
+#[repr(transparent)]
+pub(crate) struct NapiDeferred(raw::Deferred);
+
+// # Safety
+// A `napi_deferred` may be moved to any thread, but it may only be settled on the
+// JavaScript thread that created it. Settling requires a `Context`, which serializes
+// access in the same way as `NapiRef`.
+unsafe impl Send for NapiDeferred {}
+unsafe impl Sync for NapiDeferred {}
+
+impl NapiDeferred {
+    /// Rejects the promise with an `Error` indicating the `Deferred` was leaked.
+    ///
+    /// # Safety
+    /// `env` must be a valid `napi_env` for the thread that created the promise
+    #[cfg(feature = "napi-6")]
+    pub(crate) unsafe fn leaked(self, env: raw::Env) {
+        let msg = "`neon::types::Deferred` was dropped without being settled";
+        let mut local: raw::Local = std::mem::zeroed();
+        let mut err: raw::Local = std::mem::zeroed();
+
+        assert!(neon_runtime::string::new(
+            &mut local,
+            env,
+            msg.as_ptr(),
+            msg.len() as i32
+        ));
+
+        neon_runtime::error::new_error(env, &mut err, local);
+        neon_runtime::promise::reject(env, self.0, err);
+    }
+}
+
+/// A handle for settling (resolving or rejecting) a [`JsPromise`](JsPromise).
+///
+/// A `Deferred` is `Send` and may be moved to another thread. However, it may
+/// only be settled on the JavaScript thread that created the promise. Use
+/// [`Deferred::settle_with`](Deferred::settle_with) to settle a promise from
+/// another thread through a [`Channel`](crate::event::Channel).
+///
+/// A `Deferred` _should_ be settled exactly once. If a `Deferred` is dropped
+/// without being settled:
+/// * N-API < 6, Neon will `panic` to notify of the leak
+/// * N-API >= 6, Neon will reject the promise with an `Error` from a global queue
+pub struct Deferred {
+    // `Option` is used to skip `Drop` when the `Deferred` is settled.
+    // It will *always* be `Some` when a user is interacting with `Deferred`.
+    internal: Option<NapiDeferred>,
+    #[cfg(feature = "napi-6")]
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
+}
+
+impl std::fmt::Debug for Deferred {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Deferred")
+    }
+}
+
+impl Deferred {
+    /// Resolves the promise with `value`.
+    pub fn resolve<'a, V: Value, C: Context<'a>>(self, cx: &mut C, value: Handle<V>) {
+        unsafe {
+            neon_runtime::promise::resolve(cx.env().to_raw(), self.into_inner(), value.to_raw());
+        }
+    }
+
+    /// Rejects the promise with `value`.
+    pub fn reject<'a, V: Value, C: Context<'a>>(self, cx: &mut C, value: Handle<V>) {
+        unsafe {
+            neon_runtime::promise::reject(cx.env().to_raw(), self.into_inner(), value.to_raw());
+        }
+    }
+
+    #[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+    /// Settles the promise on the JavaScript thread of `channel` with the result
+    /// of `complete`. The promise is resolved with the returned value, or rejected
+    /// with the exception if `complete` throws.
+    ///
+    /// Returns a [`SendError`](crate::event::SendError) holding the `Deferred` and
+    /// `complete` if the closure could not be scheduled, e.g., because the queue of a
+    /// bounded channel is full, so that settling may be tried again.
+    pub fn try_settle_with<V, F>(
+        self,
+        channel: &Channel,
+        complete: F,
+    ) -> Result<(), SendError<(Deferred, F)>>
+    where
+        V: Value,
+        F: FnOnce(TaskContext) -> JsResult<V> + Send + 'static,
+    {
+        channel
+            .schedule(Settle::new(self, complete), CallMode::NonBlocking)
+            .map_err(|err| err.map(|settle| (settle.deferred, settle.complete)))
+    }
+
+    #[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+    /// Settles the promise on the JavaScript thread of `channel` with the result
+    /// of `complete`. See [`Deferred::try_settle_with`](Deferred::try_settle_with).
+    ///
+    /// Blocks while the queue of a bounded channel is full. Panics if the closure
+    /// could not be scheduled.
+    pub fn settle_with<V, F>(self, channel: &Channel, complete: F)
+    where
+        V: Value,
+        F: FnOnce(TaskContext) -> JsResult<V> + Send + 'static,
+    {
+        let settle = Settle::new(self, complete);
+
+        channel.send(move |cx| settle.call(cx))
+    }
+
+    fn into_inner(mut self) -> raw::Deferred {
+        self.internal
+            .take()
+            // `unwrap` will not `panic` because this is the only method place
+            // `internal` is replaced with `None` and it consumes `self`.
+            .unwrap()
+            .0
+    }
+}
+
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+/// Settles a `Deferred` with the result of `complete` on the JavaScript thread
+struct Settle<V, F> {
+    deferred: Deferred,
+    complete: F,
+    _value: PhantomData<fn() -> V>,
+}
+
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+impl<V, F> Settle<V, F> {
+    fn new(deferred: Deferred, complete: F) -> Self {
+        Self {
+            deferred,
+            complete,
+            _value: PhantomData,
+        }
+    }
+}
+
+#[cfg(all(feature = "napi-4", feature = "event-queue-api"))]
+impl<V, F> ChannelTask for Settle<V, F>
+where
+    V: Value,
+    F: FnOnce(TaskContext) -> JsResult<V> + Send + 'static,
+{
+    fn call(self, cx: TaskContext) -> NeonResult<()> {
+        let env = cx.env();
+
+        // The scope of the `TaskContext` outlives the closure, so the resulting
+        // handle is still valid after `cx` has been moved into `complete`.
+        let result = (self.complete)(cx);
+
+        unsafe {
+            let deferred = self.deferred.into_inner();
+            let mut exception: raw::Local = std::mem::zeroed();
+
+            if neon_runtime::error::catch_error(env.to_raw(), &mut exception) {
+                neon_runtime::promise::reject(env.to_raw(), deferred, exception);
+            } else if let Ok(value) = result {
+                neon_runtime::promise::resolve(env.to_raw(), deferred, value.to_raw());
+            } else {
+                // `Err(Throw)` was returned without throwing. Panicking would abort
+                // the process from the threadsafe function callback; reject with an
+                // `Error` instead.
+                let msg = "settle_with callback returned Err(Throw) without throwing";
+                let mut msg_local: raw::Local = std::ptr::null_mut();
+                let mut err: raw::Local = std::ptr::null_mut();
+
+                assert!(neon_runtime::string::new(
+                    &mut msg_local,
+                    env.to_raw(),
+                    msg.as_ptr(),
+                    msg.len() as i32
+                ));
+                neon_runtime::error::new_error(env.to_raw(), &mut err, msg_local);
+                neon_runtime::promise::reject(env.to_raw(), deferred, err);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Deferred {
+    #[cfg(not(feature = "napi-6"))]
+    fn drop(&mut self) {
+        // If `None`, the `Deferred` has already been settled
+        if self.internal.is_none() {
+            return;
+        }
+
+        // Destructors are called during stack unwinding, prevent a double
+        // panic and instead prefer to leak.
+        if std::thread::panicking() {
+            eprintln!("Warning: neon::types::Deferred leaked during a panic");
+            return;
+        }
+
+        // Only panic if the event loop is still running
+        if let Ok(true) = crate::context::internal::IS_RUNNING.try_with(|v| *v.borrow()) {
+            panic!("Must settle `neon::types::Deferred` by calling `resolve` or `reject`");
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn drop(&mut self) {
+        // If `None`, the `Deferred` has already been settled
+        if let Some(internal) = self.internal.take() {
+            let _ = self.drop_queue.call(DropData::Deferred(internal), None);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use super::{build, JsFunction, JsString, JsValue, Value, ValueInternal};
+use crate::context::internal::Env;
+use crate::context::Context;
+use crate::handle::{Handle, Managed};
+use crate::object::{Object, PropertyKey};
+use crate::result::{JsResult, NeonResult};
+use neon_runtime;
+use neon_runtime::raw;
+
+/// A JavaScript symbol primitive value.
+///
+/// Symbols are unique values that can be used as property keys, since `Handle<JsSymbol>`
+/// implements [`PropertyKey`](crate::object::PropertyKey). Well-known symbols, such as
+/// `Symbol.iterator`, are read from the global `Symbol` object.
+///
+/// ## Example
+///
+/// ```
+/// # #[cfg(feature = "napi-1")] {
+/// # use neon::prelude::*;
+/// use neon::types::JsSymbol;
+///
+/// fn tag_object(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let obj = cx.empty_object();
+///     let tag = JsSymbol::well_known(&mut cx, "toStringTag")?;
+///     let name = cx.string("Tagged");
+///
+///     obj.set(&mut cx, tag, name)?;
+///
+///     Ok(obj)
+/// }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JsSymbol(raw::Local);
+
+impl JsSymbol {
+    /// Creates a new unique symbol with a description
+    pub fn new<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        description: S,
+    ) -> Handle<'a, JsSymbol> {
+        let description = cx.string(description);
+
+        JsSymbol::new_internal(cx.env(), description.to_raw())
+    }
+
+    /// Creates a new unique symbol without a description
+    pub fn without_description<'a, C: Context<'a>>(cx: &mut C) -> Handle<'a, JsSymbol> {
+        JsSymbol::new_internal(cx.env(), std::ptr::null_mut())
+    }
+
+    pub(crate) fn new_internal<'a>(env: Env, description: raw::Local) -> Handle<'a, JsSymbol> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            neon_runtime::primitive::symbol(&mut local, env.to_raw(), description);
+            Handle::new_internal(JsSymbol(local))
+        }
+    }
+
+    /// Gets a symbol from the global symbol registry, creating it if it does not
+    /// exist, as with `Symbol.for(key)`
+    ///
+    /// For example, `JsSymbol::for_key(cx, "nodejs.util.inspect.custom")` returns
+    /// Node's `util.inspect.custom` symbol.
+    pub fn for_key<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        key: S,
+    ) -> JsResult<'a, JsSymbol> {
+        let symbol = global_symbol(cx)?;
+        let for_fn = symbol
+            .get(cx, "for")?
+            .downcast_or_throw::<JsFunction, _>(cx)?;
+        let key = cx.string(key);
+
+        for_fn.call(cx, symbol, vec![key])?.downcast_or_throw(cx)
+    }
+
+    /// Gets a well-known symbol by its name on the global `Symbol` object, e.g.,
+    /// `"iterator"` for `Symbol.iterator`. Throws a `TypeError` if no symbol with
+    /// that name exists.
+    pub fn well_known<'a, C: Context<'a>>(cx: &mut C, name: &str) -> JsResult<'a, JsSymbol> {
+        global_symbol(cx)?.get(cx, name)?.downcast_or_throw(cx)
+    }
+
+    /// Gets the well-known `Symbol.iterator` symbol
+    pub fn iterator<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        JsSymbol::well_known(cx, "iterator")
+    }
+
+    /// Gets the well-known `Symbol.asyncIterator` symbol
+    pub fn async_iterator<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        JsSymbol::well_known(cx, "asyncIterator")
+    }
+
+    /// Gets the symbol's description, or `None` if it was created without one
+    pub fn description<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<Option<String>> {
+        let description: Handle<JsValue> = build(cx.env(), |out| unsafe {
+            "description".get_from(cx, out, self.to_raw())
+        })?;
+
+        Ok(description
+            .downcast::<JsString, _>(cx)
+            .ok()
+            .map(|description| description.value(cx)))
+    }
+}
+
+fn global_symbol<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsFunction> {
+    cx.global().get(cx, "Symbol")?.downcast_or_throw(cx)
+}
+
+impl Value for JsSymbol {}
+
+impl Managed for JsSymbol {
+    fn to_raw(self) -> raw::Local {
+        self.0
+    }
+
+    fn from_raw(_: Env, h: raw::Local) -> Self {
+        JsSymbol(h)
+    }
+}
+
+impl ValueInternal for JsSymbol {
+    fn name() -> String {
+        "symbol".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_symbol(env.to_raw(), other.to_raw()) }
+    }
+}
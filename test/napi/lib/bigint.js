@@ -0,0 +1,93 @@
+var addon = require('..');
+var assert = require('chai').assert;
+
+describe('JsBigInt', function() {
+  const I64_MAX = 2n ** 63n - 1n;
+  const I64_MIN = -(2n ** 63n);
+  const U64_MAX = 2n ** 64n - 1n;
+  const I128_MAX = 2n ** 127n - 1n;
+  const I128_MIN = -(2n ** 127n);
+  const U128_MAX = 2n ** 128n - 1n;
+
+  it('should check the type of a BigInt', function () {
+    assert.isTrue(addon.is_bigint(42n));
+    assert.isFalse(addon.is_bigint(42));
+    assert.isFalse(addon.is_bigint('42'));
+  });
+
+  it('should round trip i64 values', function () {
+    for (const n of [0n, 1n, -1n, I64_MAX, I64_MIN]) {
+      assert.strictEqual(addon.bigint_i64_roundtrip(n), n);
+    }
+  });
+
+  it('should report lossy i64 conversions', function () {
+    assert.throws(() => addon.bigint_i64_roundtrip(I64_MAX + 1n), RangeError);
+    assert.throws(() => addon.bigint_i64_roundtrip(I64_MIN - 1n), RangeError);
+    assert.strictEqual(addon.bigint_i64_lossy(I64_MAX + 1n), BigInt.asIntN(64, I64_MAX + 1n));
+  });
+
+  it('should round trip u64 values', function () {
+    for (const n of [0n, 1n, U64_MAX]) {
+      assert.strictEqual(addon.bigint_u64_roundtrip(n), n);
+    }
+  });
+
+  it('should report lossy u64 conversions', function () {
+    assert.throws(() => addon.bigint_u64_roundtrip(-1n), RangeError);
+    assert.throws(() => addon.bigint_u64_roundtrip(U64_MAX + 1n), RangeError);
+    assert.strictEqual(addon.bigint_u64_lossy(-1n), U64_MAX);
+  });
+
+  it('should round trip i128 values', function () {
+    for (const n of [0n, 1n, -1n, I64_MIN, U64_MAX, -U64_MAX, I128_MAX, I128_MIN]) {
+      assert.strictEqual(addon.bigint_i128_roundtrip(n), n);
+    }
+  });
+
+  it('should report lossy i128 conversions', function () {
+    assert.throws(() => addon.bigint_i128_roundtrip(I128_MAX + 1n), RangeError);
+    assert.throws(() => addon.bigint_i128_roundtrip(I128_MIN - 1n), RangeError);
+    assert.throws(() => addon.bigint_i128_roundtrip(2n ** 200n), RangeError);
+
+    for (const n of [I128_MAX + 1n, I128_MIN - 1n, 2n ** 200n + 5n, -(2n ** 200n) - 5n]) {
+      assert.strictEqual(addon.bigint_i128_lossy(n), BigInt.asIntN(128, n));
+    }
+  });
+
+  it('should round trip u128 values', function () {
+    for (const n of [0n, 1n, U64_MAX, U64_MAX + 1n, U128_MAX]) {
+      assert.strictEqual(addon.bigint_u128_roundtrip(n), n);
+    }
+  });
+
+  it('should report lossy u128 conversions', function () {
+    assert.throws(() => addon.bigint_u128_roundtrip(-1n), RangeError);
+    assert.throws(() => addon.bigint_u128_roundtrip(U128_MAX + 1n), RangeError);
+
+    for (const n of [-1n, -U64_MAX, U128_MAX + 2n]) {
+      assert.strictEqual(addon.bigint_u128_lossy(n), BigInt.asUintN(128, n));
+    }
+  });
+
+  it('should convert a BigInt to words', function () {
+    assert.deepEqual(addon.bigint_to_words(0n), { negative: false, words: [] });
+    assert.deepEqual(addon.bigint_to_words(-5n), { negative: true, words: [5n] });
+    assert.deepEqual(addon.bigint_to_words(2n ** 130n + 7n), {
+      negative: false,
+      words: [7n, 0n, 4n]
+    });
+  });
+
+  it('should create a BigInt from words', function () {
+    assert.strictEqual(addon.bigint_from_words(false, []), 0n);
+    assert.strictEqual(addon.bigint_from_words(true, [5n]), -5n);
+    assert.strictEqual(addon.bigint_from_words(false, [7n, 0n, 4n]), 2n ** 130n + 7n);
+    assert.strictEqual(addon.bigint_from_words(true, [0n, 1n, 0n, 0n]), -(2n ** 64n));
+  });
+
+  it('should read the least significant words into a buffer', function () {
+    assert.deepEqual(addon.bigint_read_low_word(2n ** 130n + 7n), [7n, 3]);
+    assert.deepEqual(addon.bigint_read_low_word(0n), [0n, 0]);
+  });
+});
@@ -0,0 +1,104 @@
+const addon = require('..');
+const assert = require('chai').assert;
+
+describe('classes', function() {
+  const { User, Counter } = addon;
+
+  it('can construct an instance', function () {
+    const person = new User('World');
+
+    assert.instanceOf(person, User);
+    assert.strictEqual(person.greet(), 'Hello, World!');
+  });
+
+  it('defines methods on the prototype', function () {
+    const a = new User('A');
+    const b = new User('B');
+
+    assert.strictEqual(a.greet, b.greet);
+    assert.strictEqual(User.prototype.greet, a.greet);
+    assert.isFalse(Object.prototype.hasOwnProperty.call(a, 'greet'));
+  });
+
+  it('can mutate with a renamed method', function () {
+    const person = new User('World');
+
+    person.rename('Neon');
+
+    assert.isUndefined(person.rename_user);
+    assert.strictEqual(person.greet(), 'Hello, Neon!');
+  });
+
+  it('can get and set accessor properties', function () {
+    const person = new User('World');
+
+    assert.strictEqual(person.name, 'World');
+    person.name = 'Neon';
+    assert.strictEqual(person.name, 'Neon');
+    assert.strictEqual(person.greet(), 'Hello, Neon!');
+  });
+
+  it('defines getters without setters', function () {
+    const person = new User('World');
+
+    assert.strictEqual(person.shout, 'WORLD');
+    person.shout = 'ignored';
+    assert.strictEqual(person.shout, 'WORLD');
+  });
+
+  it('defines static methods', function () {
+    assert.strictEqual(User.default_name(), 'Anonymous');
+    assert.isUndefined(User.anonymous);
+  });
+
+  it('propagates exceptions', function () {
+    const person = new User('World');
+
+    assert.throws(() => person.fail(), /User failed/);
+  });
+
+  it('throws when called without new', function () {
+    assert.throws(() => User('World'), TypeError, /without 'new'/);
+  });
+
+  it('throws when the constructor throws', function () {
+    assert.throws(() => new User(42), TypeError);
+  });
+
+  it('throws when `this` is not an instance', function () {
+    const person = new User('World');
+    const counter = new Counter();
+
+    assert.throws(() => person.greet.call({}), TypeError, /Illegal invocation/);
+    assert.throws(() => person.greet.call(counter), TypeError, /Illegal invocation/);
+    assert.throws(() => Object.getOwnPropertyDescriptor(User.prototype, 'name').get.call(counter), TypeError);
+  });
+
+  it('keeps independent state per instance', function () {
+    const a = new Counter();
+    const b = new Counter();
+
+    assert.strictEqual(a.increment(), 1);
+    assert.strictEqual(a.increment(), 2);
+    assert.strictEqual(b.increment(), 1);
+  });
+
+  it('can be subclassed', function () {
+    class Child extends User {
+      greet() {
+        return super.greet() + ' (child)';
+      }
+    }
+
+    const child = new Child('World');
+
+    assert.instanceOf(child, User);
+    assert.strictEqual(child.greet(), 'Hello, World! (child)');
+  });
+
+  it('dynamically checks borrowing rules', function () {
+    const counter = new Counter();
+
+    assert.throws(() => counter.increment_with(() => counter.increment()), /already borrowed|BorrowError|BorrowMutError/);
+  });
+});
@@ -7,4 +7,48 @@ describe('coercions', function() {
     assert.strictEqual(addon.to_string(new Map()), '[object Map]');
     assert.strictEqual(addon.to_string({ a: 'b' }), '[object Object]');
   });
+
+  it('propagates exceptions from stringifying', function () {
+    assert.throws(() => addon.to_string(Symbol('s')), TypeError);
+    assert.throws(() => addon.to_string({ toString() { throw new Error('toString threw'); } }), /toString threw/);
+  });
+
+  it('can convert to a number', function () {
+    assert.strictEqual(addon.to_number('42'), 42);
+    assert.strictEqual(addon.to_number(' 1.5 '), 1.5);
+    assert.strictEqual(addon.to_number(new Number(7)), 7);
+    assert.strictEqual(addon.to_number({ valueOf() { return 3; } }), 3);
+    assert.strictEqual(addon.to_number(true), 1);
+    assert.strictEqual(addon.to_number(null), 0);
+    assert.strictEqual(addon.to_number(''), 0);
+    assert.isNaN(addon.to_number(undefined));
+    assert.isNaN(addon.to_number('forty-two'));
+  });
+
+  it('propagates exceptions from converting to a number', function () {
+    assert.throws(() => addon.to_number({ valueOf() { throw new Error('valueOf threw'); } }), /valueOf threw/);
+    assert.throws(() => addon.to_number(Symbol('s')), TypeError);
+    assert.throws(() => addon.to_number(1n), TypeError);
+  });
+
+  it('can convert to a boolean', function () {
+    for (const value of [0, -0, NaN, '', null, undefined, false, 0n]) {
+      assert.isFalse(addon.to_boolean(value), String(value));
+    }
+
+    for (const value of [1, 'false', {}, [], Symbol('s'), new Boolean(false), 1n]) {
+      assert.isTrue(addon.to_boolean(value), String(value));
+    }
+  });
+
+  it('can convert to an object', function () {
+    const obj = {};
+
+    assert.strictEqual(addon.to_object(obj), obj);
+    assert.instanceOf(addon.to_object(1), Number);
+    assert.strictEqual(addon.to_object('abc').length, 3);
+    assert.strictEqual(typeof addon.to_object(Symbol('s')), 'object');
+    assert.throws(() => addon.to_object(null), TypeError);
+    assert.throws(() => addon.to_object(undefined), TypeError);
+  });
 });
@@ -39,4 +39,170 @@ describe('errors', function() {
     assert.strictEqual(msg, "failed to downcast string to number");
   });
 
+  describe('builder', function () {
+    it('should set a code', function () {
+      const err = addon.new_error_with_code("Expected a string", "ERR_INVALID_ARG_TYPE");
+
+      assert.instanceOf(err, TypeError);
+      assert.strictEqual(err.message, "Expected a string");
+      assert.strictEqual(err.code, "ERR_INVALID_ARG_TYPE");
+    });
+
+    it('should set a non-enumerable cause', function () {
+      const cause = new Error("ENOENT");
+      const err = addon.new_error_with_cause(cause);
+
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.cause, cause);
+      assert.isFalse(Object.prototype.propertyIsEnumerable.call(err, 'cause'));
+    });
+
+    it('should set a name and extra properties', function () {
+      const err = addon.new_error_with_properties();
+
+      assert.instanceOf(err, RangeError);
+      assert.strictEqual(err.name, "ConfigError");
+      assert.strictEqual(err.code, "ERR_CONFIG");
+      assert.strictEqual(err.path, "/etc/app.toml");
+      assert.strictEqual(err.line, 3);
+      assert.strictEqual(String(err), "ConfigError: Invalid config");
+      assert.sameMembers(Object.keys(err), ['code', 'path', 'line']);
+    });
+
+    it('should throw an error with a code', function () {
+      assert.throws(() => addon.throw_error_with_code("ERR_OH_NO"), Error, "Oh, no!");
+
+      try {
+        addon.throw_error_with_code("ERR_OH_NO");
+      } catch (err) {
+        assert.strictEqual(err.code, "ERR_OH_NO");
+      }
+    });
+  });
+  describe('or_throw', function () {
+    it('should throw a Rust error as an Error', function () {
+      assert.throws(() => addon.read_file_or_throw("/does/not/exist"), Error, /No such file/);
+    });
+
+    it('should throw a RangeError for integer conversions', function () {
+      assert.strictEqual(addon.parse_u8_or_throw(255), 255);
+      assert.throws(() => addon.parse_u8_or_throw(256), RangeError);
+    });
+
+    it('should use a custom class and code with a cause chain', function () {
+      assert.strictEqual(addon.load_settings_or_throw("42"), 42);
+
+      try {
+        addon.load_settings_or_throw("forty-two");
+        assert.fail("expected an exception");
+      } catch (err) {
+        assert.instanceOf(err, TypeError);
+        assert.strictEqual(err.message, "Failed to load settings");
+        assert.strictEqual(err.code, "ERR_LOAD_SETTINGS");
+        assert.instanceOf(err.cause, Error);
+        assert.strictEqual(err.cause.message, "invalid digit found in string");
+        assert.isUndefined(err.cause.cause);
+      }
+    });
+
+    it('should ignore a custom conversion when throwing the default error', function () {
+      try {
+        addon.load_settings_or_throw_default("forty-two");
+        assert.fail("expected an exception");
+      } catch (err) {
+        assert.strictEqual(Object.getPrototypeOf(err), Error.prototype);
+        assert.strictEqual(err.message, "Failed to load settings");
+        assert.isUndefined(err.code);
+        assert.instanceOf(err.cause, Error);
+        assert.strictEqual(err.cause.message, "invalid digit found in string");
+      }
+    });
+
+    it('should limit the depth of a self-referential cause chain', function () {
+      function causeDepth(fn) {
+        try {
+          fn();
+        } catch (err) {
+          let depth = 0;
+
+          for (let cause = err.cause; cause !== undefined; cause = cause.cause) {
+            assert.strictEqual(cause.message, "Cyclic error");
+            depth++;
+          }
+
+          return depth;
+        }
+
+        assert.fail("expected an exception");
+      }
+
+      assert.strictEqual(causeDepth(() => addon.cyclic_error_or_throw()), 32);
+      assert.strictEqual(causeDepth(() => addon.cyclic_error_or_throw_js()), 32);
+    });
+  });
+  describe('CaughtError', function () {
+    it('should read the properties of an error', function () {
+      const details = addon.catch_error_details(() => {
+        const err = new TypeError("bad input");
+        err.code = "ERR_BAD_INPUT";
+        throw err;
+      });
+
+      assert.deepEqual(details, {
+        display: "TypeError: bad input",
+        message: "bad input",
+        name: "TypeError",
+        code: "ERR_BAD_INPUT",
+        hasStack: true
+      });
+    });
+
+    it('should read the cause chain', function () {
+      const details = addon.catch_error_details(() => {
+        const inner = new RangeError("too big");
+        const outer = new Error("request failed");
+        outer.cause = inner;
+        throw outer;
+      });
+
+      assert.strictEqual(details.display, "Error: request failed");
+      assert.strictEqual(details.cause.display, "RangeError: too big");
+      assert.isUndefined(details.cause.cause);
+    });
+
+    it('should handle non-Error throwables', function () {
+      assert.deepEqual(addon.catch_error_details(() => { throw "oops"; }), {
+        display: "oops",
+        message: "oops",
+        hasStack: false
+      });
+
+      assert.strictEqual(addon.catch_error_details(() => { throw 42; }).display, "42");
+      assert.strictEqual(addon.catch_error_details(() => { throw Symbol("sym"); }).display, "Symbol(sym)");
+      assert.deepEqual(addon.catch_error_details(() => { throw { message: "plain", code: "E_PLAIN" }; }), {
+        display: "plain",
+        message: "plain",
+        code: "E_PLAIN",
+        hasStack: false
+      });
+    });
+
+    it('should return undefined when nothing is thrown', function () {
+      assert.isUndefined(addon.catch_error_details(() => {}));
+    });
+
+    it('should expose the cause chain as Rust error sources', function () {
+      const err = new Error("a", { cause: new Error("b", { cause: "c" }) });
+
+      assert.strictEqual(addon.count_caught_error_sources(err), 2);
+      assert.strictEqual(addon.count_caught_error_sources(new Error("solo")), 0);
+    });
+
+    it('should stop at cyclic causes', function () {
+      const err = new Error("cycle");
+      err.cause = err;
+
+      assert.strictEqual(addon.count_caught_error_sources(err), 32);
+    });
+  });
 });
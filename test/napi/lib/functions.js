@@ -110,8 +110,90 @@ describe('JsFunction', function() {
     assert.strictEqual(addon.get_number_or_default(), 0);
   });
 
+  it('catches exceptions in nested cx.try_catch calls', function() {
+    assert.deepEqual(addon.nested_try_catch(() => { throw 'inner'; }, () => { throw 'outer'; }), ['inner', 'outer']);
+    assert.deepEqual(addon.nested_try_catch(() => { throw 'inner'; }, () => {}), ['inner']);
+    assert.deepEqual(addon.nested_try_catch(() => 1, () => { throw 'outer'; }), [, 'outer']);
+  });
+
+  it('returns an Error if a cx.try_catch closure returns Err(Throw) without throwing', function() {
+    const err = addon.try_catch_without_throwing();
+
+    assert.instanceOf(err, Error);
+    assert.match(err.message, /without a pending exception/);
+  });
+
+  it('clears the pending exception when a cx.try_catch closure panics', function() {
+    assert.strictEqual(addon.try_catch_after_panic(() => { throw new Error('pending'); }), 'recovered');
+  });
+
+  it('runs finally after cx.try_catch_finally', function() {
+    const calls = [];
+    const finally_ = () => { calls.push('finally'); };
+
+    assert.strictEqual(addon.try_catch_finally(() => { calls.push('try'); return 1; }, finally_), 1);
+    assert.deepEqual(calls, ['try', 'finally']);
+
+    assert.strictEqual(addon.try_catch_finally(() => { throw 'thrown'; }, finally_), 'thrown');
+    assert.deepEqual(calls, ['try', 'finally', 'finally']);
+
+    assert.strictEqual(addon.try_catch_finally(() => { throw 'thrown'; }, () => { throw 'finally'; }), 'finally');
+    assert.strictEqual(addon.try_catch_finally(() => 1, () => { throw 'finally'; }), 'finally');
+  });
+
+  it('runs finally when a cx.try_catch_finally closure panics', function() {
+    let called = false;
+
+    assert.strictEqual(addon.try_catch_finally_after_panic(() => { called = true; }), 'recovered');
+    assert.isTrue(called);
+  });
+
   it('distinguishes calls from constructs', function() {
     assert.equal(addon.is_construct.call({}).wasConstructed, false);
     assert.equal((new addon.is_construct()).wasConstructed, true);
   });
+
+  it('calls a closure with captured state', function() {
+    const a = addon.make_counter(10);
+    const b = addon.make_counter(0);
+    assert.strictEqual(a(), 11);
+    assert.strictEqual(a(), 12);
+    assert.strictEqual(b(), 1);
+    assert.strictEqual(a(), 13);
+  });
+
+  it('propagates exceptions thrown by a closure', function() {
+    const f = addon.make_throwing_closure('closure failed');
+    assert.throws(f, Error, 'closure failed');
+  });
+
+  it('converts panics in a closure to exceptions', function() {
+    const f = addon.make_panicking_closure('closure panicked');
+    assert.throws(f, Error, 'internal error in Neon module: closure panicked');
+    assert.throws(f, Error, 'internal error in Neon module: closure panicked');
+  });
+
+  it('drops a closure when its function is garbage collected', function(cb) {
+    const before = addon.count_dropped_closures();
+
+    (function () {
+      for (let i = 0; i < 10; i++) {
+        addon.make_dropping_closure()();
+      }
+    })();
+
+    let attempts = 0;
+
+    (function check() {
+      global.gc();
+
+      if (addon.count_dropped_closures() - before === 10) {
+        cb();
+      } else if (++attempts > 100) {
+        cb(new Error('closures were not dropped'));
+      } else {
+        setTimeout(check, 10);
+      }
+    })();
+  });
 });
@@ -47,4 +47,46 @@ describe('JsNumber', function() {
       assert.equal(addon.accept_and_return_negative_js_number(-55), -55);
     });
   });
+
+  describe('integers', function() {
+    it('creates numbers from integers', function () {
+      assert.deepEqual(addon.create_integers(), [-2147483648, 4294967295, -9007199254740991, 9223372036854775807]);
+    });
+
+    it('converts numbers to integers', function () {
+      assert.strictEqual(addon.number_value_i32(-42), -42);
+      assert.strictEqual(addon.number_value_i32(1.9), 1);
+      assert.strictEqual(addon.number_value_i32(-1.9), -1);
+      assert.strictEqual(addon.number_value_i32(2147483648), -2147483648);
+      assert.strictEqual(addon.number_value_i32(NaN), 0);
+      assert.strictEqual(addon.number_value_i32(Infinity), 0);
+
+      assert.strictEqual(addon.number_value_u32(4294967295), 4294967295);
+      assert.strictEqual(addon.number_value_u32(-1), 4294967295);
+      assert.strictEqual(addon.number_value_u32(4294967296), 0);
+
+      assert.strictEqual(addon.number_value_i64(-9007199254740991), '-9007199254740991');
+      assert.strictEqual(addon.number_value_i64(2.5), '2');
+      assert.strictEqual(addon.number_value_i64(NaN), '0');
+    });
+
+    it('converts integers in range', function () {
+      assert.strictEqual(addon.number_value_i32_or_throw(-2147483648), -2147483648);
+      assert.strictEqual(addon.number_value_i32_or_throw(2147483647), 2147483647);
+      assert.strictEqual(addon.number_value_i32_or_throw(-0), 0);
+      assert.strictEqual(addon.number_value_u32_or_throw(4294967295), 4294967295);
+      assert.strictEqual(addon.number_value_i64_or_throw(-9223372036854775808), '-9223372036854775808');
+      assert.strictEqual(addon.number_value_i64_or_throw(9007199254740993), '9007199254740992');
+    });
+
+    it('throws a RangeError for numbers that are not integers in range', function () {
+      assert.throws(() => addon.number_value_i32_or_throw(1.5), RangeError, /between -2147483648 and 2147483647, found 1.5/);
+      assert.throws(() => addon.number_value_i32_or_throw(2147483648), RangeError);
+      assert.throws(() => addon.number_value_i32_or_throw(NaN), RangeError);
+      assert.throws(() => addon.number_value_u32_or_throw(-1), RangeError, /between 0 and 4294967295/);
+      assert.throws(() => addon.number_value_u32_or_throw(Infinity), RangeError);
+      assert.throws(() => addon.number_value_i64_or_throw(9223372036854775808), RangeError);
+      assert.throws(() => addon.number_value_i64_or_throw(0.1), RangeError);
+    });
+  });
 });
@@ -180,4 +180,349 @@ describe('JsObject', function() {
     );
     assert.equal(addon.get_own_property_names(object).length, 1);
   });
+
+  describe('wrap', function() {
+    class Base {
+      constructor(count) {
+        addon.wrap_counter(this, count);
+      }
+    }
+
+    Base.prototype.increment = addon.increment_counter;
+
+    class Derived extends Base {
+      double() {
+        return this.increment() * 2;
+      }
+    }
+
+    it('attaches Rust state to an instance of a JavaScript class', function() {
+      const counter = new Derived(10);
+      assert.strictEqual(counter.increment(), 11);
+      assert.strictEqual(counter.double(), 24);
+      assert.instanceOf(counter, Base);
+      assert.isTrue(addon.is_counter(counter));
+    });
+
+    it('throws when an object is already wrapped', function() {
+      const obj = {};
+      addon.wrap_counter(obj, 0);
+      assert.throws(() => addon.wrap_counter(obj, 0), TypeError, 'already wrapped');
+      assert.throws(() => addon.wrap_string(obj, 'hello'), TypeError, 'already wrapped');
+    });
+
+    it('fails to unwrap an unwrapped object or a value of another type', function() {
+      const str = {};
+      addon.wrap_string(str, 'hello');
+
+      assert.isFalse(addon.is_counter({}));
+      assert.isFalse(addon.is_counter(str));
+      assert.throws(() => addon.increment_counter.call({}), TypeError, 'does not wrap');
+      assert.throws(() => addon.increment_counter.call(str), TypeError, 'does not wrap');
+    });
+
+    it('removes the wrapped value', function() {
+      const obj = {};
+      addon.wrap_counter(obj, 41);
+      addon.increment_counter.call(obj);
+
+      const str = {};
+      addon.wrap_string(str, 'hello');
+
+      assert.isUndefined(addon.remove_counter(str));
+      assert.isUndefined(addon.remove_counter({}));
+      assert.strictEqual(addon.remove_counter(obj), 42);
+      assert.isFalse(addon.is_counter(obj));
+      assert.isUndefined(addon.remove_counter(obj));
+
+      // The object can be wrapped again
+      addon.wrap_counter(obj, 1);
+      assert.strictEqual(addon.increment_counter.call(obj), 2);
+    });
+
+    it('finalizes the wrapped value when the object is garbage collected', function(cb) {
+      const before = addon.count_finalized_counters();
+
+      (function () {
+        for (let i = 0; i < 10; i++) {
+          new Derived(i);
+        }
+      })();
+
+      let attempts = 0;
+
+      (function check() {
+        global.gc();
+
+        if (addon.count_finalized_counters() - before >= 10) {
+          cb();
+        } else if (++attempts > 100) {
+          cb(new Error('wrapped values were not finalized'));
+        } else {
+          setTimeout(check, 10);
+        }
+      })();
+    });
+  });
+
+  describe('define_property', function() {
+    it('defines data properties with attributes', function () {
+      const tag = Symbol('tag');
+      const obj = addon.define_constants(tag);
+
+      assert.deepEqual(Object.getOwnPropertyDescriptor(obj, 'ANSWER'), {
+        value: 42,
+        writable: false,
+        enumerable: true,
+        configurable: false
+      });
+
+      assert.deepEqual(Object.getOwnPropertyDescriptor(obj, 'HIDDEN'), {
+        value: 'hidden',
+        writable: false,
+        enumerable: false,
+        configurable: false
+      });
+
+      assert.deepEqual(Object.getOwnPropertyDescriptor(obj, '0'), {
+        value: 'first',
+        writable: true,
+        enumerable: false,
+        configurable: false
+      });
+
+      assert.deepEqual(Object.getOwnPropertyDescriptor(obj, tag), {
+        value: true,
+        writable: false,
+        enumerable: false,
+        configurable: true
+      });
+
+      assert.deepEqual(Object.keys(obj), ['ANSWER']);
+
+      obj.ANSWER = 0;
+      assert.strictEqual(obj.ANSWER, 42);
+      obj[0] = 'second';
+      assert.strictEqual(obj[0], 'second');
+    });
+
+    it('throws when redefining a non-configurable property', function () {
+      const obj = {};
+
+      addon.define_property_value(obj, 'constant', 1);
+      assert.strictEqual(obj.constant, 1);
+      assert.throws(() => addon.define_property_value(obj, 'constant', 2), TypeError);
+      assert.strictEqual(obj.constant, 1);
+    });
+
+    it('throws when the object is not extensible', function () {
+      assert.throws(() => addon.define_property_value(Object.preventExtensions({}), 'age', 1), TypeError, /not extensible/);
+    });
+
+    it('throws when a key is not a string or symbol', function () {
+      assert.throws(() => addon.define_property_value({}, 1, 1), TypeError, /string or symbol/);
+    });
+
+    it('defines accessor properties backed by a JsBox', function () {
+      const temperature = addon.create_temperature(100);
+
+      assert.strictEqual(temperature.celsius, 100);
+      assert.strictEqual(temperature.fahrenheit, 212);
+      assert.deepEqual(Object.keys(temperature), ['celsius', 'fahrenheit']);
+      assert.isFalse(Object.getOwnPropertyDescriptor(temperature, 'inner').writable);
+
+      temperature.celsius = 0;
+      assert.strictEqual(temperature.celsius, 0);
+      assert.strictEqual(temperature.fahrenheit, 32);
+
+      // Getter-only properties ignore assignment outside of strict mode
+      temperature.fahrenheit = 0;
+      assert.strictEqual(temperature.fahrenheit, 32);
+      assert.throws(() => { 'use strict'; temperature.fahrenheit = 0; }, TypeError);
+
+      const descriptor = Object.getOwnPropertyDescriptor(temperature, 'fahrenheit');
+      assert.isFunction(descriptor.get);
+      assert.isUndefined(descriptor.set);
+      assert.isFalse(descriptor.configurable);
+
+      const celsius = Object.getOwnPropertyDescriptor(temperature, 'celsius');
+      assert.isFunction(celsius.get);
+      assert.isFunction(celsius.set);
+      assert.isTrue(celsius.enumerable);
+      assert.isFalse(celsius.configurable);
+    });
+
+    it('calls accessors after the object is garbage collected', function () {
+      const { get, set } = (() => {
+        return Object.getOwnPropertyDescriptor(addon.create_temperature(100), 'celsius');
+      })();
+
+      global.gc();
+
+      const holder = addon.create_temperature(20);
+
+      assert.strictEqual(get.call(holder), 20);
+      set.call(holder, 30);
+      assert.strictEqual(holder.celsius, 30);
+    });
+
+    it('propagates exceptions from accessors', function () {
+      const temperature = addon.create_temperature(0);
+
+      assert.throws(() => { temperature.celsius = -300; }, RangeError, /absolute zero/);
+      assert.strictEqual(temperature.celsius, 0);
+      assert.throws(() => temperature.panic, Error, /getter panicked/);
+      assert.throws(() => Object.getOwnPropertyDescriptor(temperature, 'celsius').get.call({}), TypeError);
+    });
+  });
+
+  describe('reflection', function() {
+    class Base {
+      inherited() {}
+    }
+
+    function create(tag) {
+      const obj = new Base();
+      obj.name = 'value';
+      obj[3] = 'three';
+      obj[tag] = 'tagged';
+      return obj;
+    }
+
+    it('checks whether a property exists with every key type', function () {
+      const tag = Symbol('tag');
+      const obj = create(tag);
+
+      for (const [key, expected] of [['name', true], [3, true], [tag, true], ['inherited', true], ['missing', false], [4, false], [Symbol('tag'), false]]) {
+        assert.strictEqual(addon.object_key_op(obj, 'has', key), expected, String(key));
+      }
+    });
+
+    it('checks whether an own property exists with every key type', function () {
+      const tag = Symbol('tag');
+      const obj = create(tag);
+
+      for (const [key, expected] of [['name', true], [3, true], [tag, true], ['inherited', false], ['missing', false], [4, false]]) {
+        assert.strictEqual(addon.object_key_op(obj, 'has_own', key), expected, String(key));
+      }
+
+      assert.isTrue(addon.object_has_own_handle(obj, 'name'));
+      assert.throws(() => addon.object_has_own_handle(obj, 3), TypeError, /string or symbol/);
+    });
+
+    it('deletes properties with every key type', function () {
+      const tag = Symbol('tag');
+      const obj = create(tag);
+
+      assert.isTrue(addon.object_key_op(obj, 'delete', 'name'));
+      assert.isTrue(addon.object_key_op(obj, 'delete', 3));
+      assert.isTrue(addon.object_key_op(obj, 'delete', tag));
+      assert.isTrue(addon.object_key_op(obj, 'delete', 'missing'));
+      assert.deepEqual(Reflect.ownKeys(obj), []);
+
+      Object.defineProperty(obj, 'fixed', { value: 1 });
+      assert.isFalse(addon.object_key_op(obj, 'delete', 'fixed'));
+      assert.strictEqual(obj.fixed, 1);
+    });
+
+    it('propagates exceptions from proxies', function () {
+      const proxy = new Proxy({}, {
+        has() { throw new Error('has trap'); },
+        deleteProperty() { throw new Error('delete trap'); }
+      });
+
+      assert.throws(() => addon.object_key_op(proxy, 'has', 'x'), /has trap/);
+      assert.throws(() => addon.object_key_op(proxy, 'delete', 'x'), /delete trap/);
+    });
+
+    it('gets the prototype', function () {
+      assert.strictEqual(addon.object_get_prototype(new Base()), Base.prototype);
+      assert.strictEqual(addon.object_get_prototype({}), Object.prototype);
+      assert.isNull(addon.object_get_prototype(Object.create(null)));
+    });
+
+    it('freezes objects', function () {
+      const obj = addon.object_freeze({ a: 1 });
+
+      assert.isTrue(Object.isFrozen(obj));
+      obj.a = 2;
+      obj.b = 3;
+      assert.deepEqual(obj, { a: 1 });
+    });
+
+    it('seals objects', function () {
+      const obj = addon.object_seal({ a: 1 });
+
+      assert.isTrue(Object.isSealed(obj));
+      assert.isFalse(Object.isFrozen(obj));
+      obj.a = 2;
+      obj.b = 3;
+      delete obj.a;
+      assert.deepEqual(obj, { a: 2 });
+    });
+  });
+
+  describe('property enumeration', function() {
+    const tag = Symbol('tag');
+
+    function create() {
+      const proto = { inherited: 1, [Symbol('inheritedTag')]: 2 };
+      Object.defineProperty(proto, 'hiddenInherited', { value: 3, enumerable: false });
+
+      const obj = Object.create(proto);
+      obj.b = 'b';
+      obj[1] = 'one';
+      obj[tag] = 'tagged';
+      Object.defineProperty(obj, 'readOnly', { value: 'r', enumerable: true, writable: false, configurable: true });
+      Object.defineProperty(obj, 'hidden', { value: 'h', enumerable: false, writable: true, configurable: false });
+
+      return obj;
+    }
+
+    it('collects all own keys by default', function () {
+      assert.deepEqual(addon.get_property_names(create(), {}), Reflect.ownKeys(create()));
+    });
+
+    it('collects inherited keys', function () {
+      const names = addon.get_property_names(create(), { includePrototypes: true, enumerable: true, skipSymbols: true });
+
+      assert.deepEqual(names, ['1', 'b', 'readOnly', 'inherited']);
+    });
+
+    it('filters keys by attribute', function () {
+      assert.deepEqual(addon.get_property_names(create(), { writable: true }), ['1', 'b', 'hidden', tag]);
+      assert.deepEqual(addon.get_property_names(create(), { enumerable: true }), ['1', 'b', 'readOnly', tag]);
+      assert.deepEqual(addon.get_property_names(create(), { configurable: true }), ['1', 'b', 'readOnly', tag]);
+      assert.deepEqual(addon.get_property_names(create(), { enumerable: true, writable: true }), ['1', 'b', tag]);
+    });
+
+    it('skips strings or symbols', function () {
+      assert.deepEqual(addon.get_property_names(create(), { skipStrings: true }), [tag]);
+      assert.deepEqual(addon.get_property_names(create(), { skipSymbols: true }), ['1', 'b', 'readOnly', 'hidden']);
+    });
+
+    it('converts index keys', function () {
+      assert.deepEqual(addon.get_property_names(['a', 'b'], { enumerable: true, keepNumbers: true }), [0, 1]);
+      assert.deepEqual(addon.get_property_names(['a', 'b'], { enumerable: true }), ['0', '1']);
+    });
+
+    it('returns entries', function () {
+      const obj = create();
+
+      assert.deepEqual(addon.get_entries(obj, { enumerable: true, skipSymbols: true }), Object.entries(obj));
+      assert.deepEqual(addon.get_entries(obj, { skipStrings: true }), [[tag, 'tagged']]);
+      assert.deepEqual(
+        addon.get_entries(obj, { includePrototypes: true, enumerable: true, skipSymbols: true }),
+        [['1', 'one'], ['b', 'b'], ['readOnly', 'r'], ['inherited', 1]]
+      );
+    });
+
+    it('propagates exceptions from proxies', function () {
+      const proxy = new Proxy({}, {
+        ownKeys() { throw new Error('ownKeys trap'); }
+      });
+
+      assert.throws(() => addon.get_property_names(proxy, {}), /ownKeys trap/);
+    });
+  });
 });
@@ -0,0 +1,128 @@
+const addon = require('..');
+const assert = require('chai').assert;
+
+describe('JsPromise', function() {
+  it('should resolve a promise', async function () {
+    const value = await addon.resolve_promise(42);
+
+    assert.strictEqual(value, 42);
+  });
+
+  it('should reject a promise', async function () {
+    const err = new Error('Oh, no!');
+
+    try {
+      await addon.reject_promise(err);
+    } catch (e) {
+      assert.strictEqual(e, err);
+      return;
+    }
+
+    throw new Error('Expected promise to reject');
+  });
+
+  it('should identify promises', function () {
+    assert.isTrue(addon.is_promise(Promise.resolve()));
+    assert.isTrue(addon.is_promise(addon.resolve_promise(1)));
+    assert.isFalse(addon.is_promise({ then() {} }));
+    assert.isFalse(addon.is_promise(42));
+  });
+
+  it('should settle a promise from another thread', async function () {
+    const value = await addon.settle_promise_from_thread(21);
+
+    assert.strictEqual(value, 42);
+  });
+
+  it('should reject a promise when settling throws', async function () {
+    try {
+      await addon.reject_promise_from_thread('Oh, no!');
+    } catch (e) {
+      assert.instanceOf(e, Error);
+      assert.strictEqual(e.message, 'Oh, no!');
+      return;
+    }
+
+    throw new Error('Expected promise to reject');
+  });
+
+  it('should reject when settle_with returns Throw without throwing', async function () {
+    try {
+      await addon.settle_promise_unexpected_throw();
+    } catch (e) {
+      assert.instanceOf(e, Error);
+      assert.match(e.message, /returned Err\(Throw\) without throwing/);
+      return;
+    }
+
+    throw new Error('Expected promise to reject');
+  });
+
+  it('should retry settling a promise when a bounded channel is full', async function () {
+    const value = await addon.retry_settle_promise(21);
+
+    assert.strictEqual(value, 42);
+  });
+
+  it('should reject a promise when the deferred is leaked', async function () {
+    // The drop queue does not keep the event loop alive; hold it open with a timer
+    const timer = setTimeout(() => {}, 10000);
+
+    try {
+      await addon.leak_deferred();
+    } catch (e) {
+      assert.instanceOf(e, Error);
+      assert.match(e.message, /dropped without being settled/);
+      return;
+    } finally {
+      clearTimeout(timer);
+    }
+
+    throw new Error('Expected promise to reject');
+  });
+});
+
+describe('cx.spawn_promise', function() {
+  it('should resolve with the output of the future', async function () {
+    assert.strictEqual(await addon.spawn_promise(21), 42);
+  });
+
+  it('should reject when complete throws', async function () {
+    try {
+      await addon.spawn_promise_throw('Oh, no!');
+    } catch (e) {
+      assert.instanceOf(e, Error);
+      assert.strictEqual(e.message, 'Oh, no!');
+      return;
+    }
+
+    throw new Error('Expected promise to reject');
+  });
+
+  it('should reject when the future panics', async function () {
+    try {
+      await addon.spawn_promise_panic();
+    } catch (e) {
+      assert.instanceOf(e, Error);
+      assert.match(e.message, /spawned future panicked/);
+      return;
+    }
+
+    throw new Error('Expected promise to reject');
+  });
+
+  it('should shut down the runtime when a worker exits', function (done) {
+    const { Worker } = require('worker_threads');
+    const worker = new Worker(`
+      const { parentPort } = require('worker_threads');
+      const addon = require(${JSON.stringify(require.resolve('..'))});
+
+      addon.spawn_promise_pending();
+      parentPort.postMessage('spawned');
+    `, { eval: true });
+
+    worker.once('error', done);
+    worker.once('message', () => worker.terminate());
+    worker.once('exit', () => done());
+  });
+});
@@ -16,4 +16,62 @@ describe('JsString', function() {
       expect(() => addon.run_string_as_script('invalid js code')).to.throw(SyntaxError);
     });
   })
+
+  describe('UTF-16', function () {
+    function units(s) {
+      return Uint16Array.from({ length: s.length }, (_, i) => s.charCodeAt(i));
+    }
+
+    it('should read UTF-16 code units', function () {
+      const s = 'h\u00e9llo \ud83d\ude00';
+
+      assert.deepEqual(addon.string_to_utf16(s), units(s));
+      assert.deepEqual(addon.string_to_utf16(''), new Uint16Array(0));
+      assert.strictEqual(addon.string_utf16_len(s), s.length);
+    });
+
+    it('should preserve unpaired surrogates', function () {
+      const lone = 'a\ud800b\udfff';
+
+      assert.deepEqual(Array.from(addon.string_to_utf16(lone)), [0x61, 0xd800, 0x62, 0xdfff]);
+      assert.strictEqual(addon.string_from_utf16(Uint16Array.from([0x61, 0xd800, 0x62, 0xdfff])), lone);
+    });
+
+    it('should create strings from UTF-16 code units', function () {
+      assert.strictEqual(addon.string_from_utf16(Uint16Array.from([0x68, 0x69, 0xd83d, 0xde00])), 'hi\ud83d\ude00');
+      assert.strictEqual(addon.string_from_utf16(new Uint16Array(0)), '');
+    });
+
+    it('should read into a buffer, leaving room for a terminator', function () {
+      assert.deepEqual(addon.string_read_utf16('hello', 8), units('hello'));
+      assert.deepEqual(addon.string_read_utf16('hello', 6), units('hello'));
+      assert.deepEqual(Array.from(addon.string_read_utf16('hello', 3)), [0x68, 0x65]);
+      assert.deepEqual(Array.from(addon.string_read_utf16('hello', 0)), []);
+    });
+
+    it('should round trip', function () {
+      const s = 'Stra\u00dfe \u65e5\u672c \ud800';
+
+      assert.strictEqual(addon.string_from_utf16(addon.string_to_utf16(s)), s);
+      assert.deepEqual(addon.string_to_utf16(s), units(s));
+    });
+  });
+
+  describe('Latin-1', function () {
+    it('should create strings from Latin-1 bytes', function () {
+      assert.strictEqual(addon.string_from_latin1(Uint8Array.from([0x63, 0x61, 0x66, 0xe9])), 'caf\u00e9');
+      assert.strictEqual(addon.string_from_latin1(Uint8Array.from([0xff, 0x00, 0x41])), '\u00ff\u0000A');
+    });
+
+    it('should read Latin-1 bytes', function () {
+      assert.deepEqual(Array.from(addon.string_to_latin1('caf\u00e9')), [0x63, 0x61, 0x66, 0xe9]);
+      // Characters outside of Latin-1 are truncated to their lowest byte
+      assert.deepEqual(Array.from(addon.string_to_latin1('\u0141')), [0x41]);
+    });
+
+    it('should read into a buffer, leaving room for a terminator', function () {
+      assert.deepEqual(Array.from(addon.string_read_latin1('caf\u00e9', 8)), [0x63, 0x61, 0x66, 0xe9]);
+      assert.deepEqual(Array.from(addon.string_read_latin1('caf\u00e9', 3)), [0x63, 0x61]);
+    });
+  });
 });
@@ -0,0 +1,57 @@
+var addon = require('..');
+var assert = require('chai').assert;
+var util = require('util');
+
+describe('JsSymbol', function() {
+  it('should create a symbol with a description', function () {
+    const symbol = addon.create_symbol('neon');
+    assert.typeOf(symbol, 'symbol');
+    assert.strictEqual(symbol.description, 'neon');
+    assert.notStrictEqual(symbol, addon.create_symbol('neon'));
+  });
+
+  it('should create a symbol without a description', function () {
+    const symbol = addon.create_symbol_without_description();
+    assert.typeOf(symbol, 'symbol');
+    assert.isUndefined(symbol.description);
+  });
+
+  it('should read the description of a symbol', function () {
+    assert.strictEqual(addon.get_symbol_description(Symbol('hello')), 'hello');
+    assert.strictEqual(addon.get_symbol_description(Symbol('')), '');
+    assert.isUndefined(addon.get_symbol_description(Symbol()));
+  });
+
+  it('should get well-known symbols', function () {
+    assert.strictEqual(addon.get_well_known_symbol('iterator'), Symbol.iterator);
+    assert.strictEqual(addon.get_well_known_symbol('asyncIterator'), Symbol.asyncIterator);
+    assert.throws(() => addon.get_well_known_symbol('notASymbol'), TypeError);
+  });
+
+  it('should get symbols from the global registry', function () {
+    assert.strictEqual(addon.get_registered_symbol('neon.test'), Symbol.for('neon.test'));
+    assert.strictEqual(addon.get_registered_symbol('nodejs.util.inspect.custom'), util.inspect.custom);
+  });
+
+  it('should check the type of a symbol', function () {
+    assert.isTrue(addon.is_symbol(Symbol()));
+    assert.isFalse(addon.is_symbol('symbol'));
+    assert.isFalse(addon.is_symbol({}));
+  });
+
+  it('should get and set symbol-keyed properties', function () {
+    const key = Symbol('key');
+    const obj = { [key]: 42 };
+    assert.strictEqual(addon.get_symbol_property(obj, key), 42);
+    assert.isUndefined(addon.get_symbol_property(obj, Symbol('key')));
+    assert.isTrue(addon.set_symbol_property(obj, key, 'value'));
+    assert.strictEqual(obj[key], 'value');
+    assert.deepEqual(Object.keys(obj), []);
+  });
+
+  it('should implement protocols with well-known symbols', function () {
+    const counter = addon.create_counter();
+    assert.deepEqual([...counter], [0, 1, 2]);
+    assert.strictEqual(util.inspect(counter), '<counter>');
+  });
+});
@@ -0,0 +1,131 @@
+const addon = require('..');
+const assert = require('chai').assert;
+
+describe('Task', function() {
+  it('completes a successful task', function (done) {
+    addon.perform_async_task((err, n) => {
+      if (err) {
+        done(err);
+      } else if (n === 17) {
+        done();
+      } else {
+        done(new Error("not 17 but: " + n));
+      }
+    });
+  });
+
+  it('completes a failing task', function (done) {
+    addon.perform_failing_task((err, n) => {
+      if (err) {
+        if (err.message === 'I am a failing task') {
+          done();
+        } else {
+          done(new Error("expected error message 'I am a failing task', got: " + err.message));
+        }
+      } else {
+        done(new Error("expected task to fail, got: " + n));
+      }
+    });
+  });
+
+  it('completes a panicking task with an error', function (done) {
+    addon.perform_panicking_task((err, n) => {
+      try {
+        assert.instanceOf(err, Error);
+        assert.match(err.message, /I am a panicking task/);
+        done();
+      } catch (e) {
+        done(e);
+      }
+    });
+  });
+
+  it('completes a task with an error if complete returns Throw without throwing', function (done) {
+    addon.perform_unexpected_throw_task((err, n) => {
+      try {
+        assert.instanceOf(err, Error);
+        assert.match(err.message, /without a pending exception/);
+        done();
+      } catch (e) {
+        done(e);
+      }
+    });
+  });
+
+  it('executes microtasks after callback', function () {
+    return new Promise((resolve, reject) => {
+      addon.perform_async_task((err, res) => {
+        if (err) {
+          reject(err);
+        } else {
+          resolve(res);
+        }
+      });
+    });
+  });
+
+  it('can cancel a task before it executes', function (done) {
+    const poolSize = Number(process.env.UV_THREADPOOL_SIZE || 4);
+    let remaining = poolSize + 1;
+    const finish = () => --remaining === 0 && done();
+
+    const isCancelled = addon.perform_cancelled_task(poolSize, finish, (err, n) => {
+      try {
+        assert.instanceOf(err, Error);
+        assert.strictEqual(err.message, 'Task was cancelled');
+        assert.isUndefined(n);
+        finish();
+      } catch (e) {
+        done(e);
+      }
+    });
+
+    assert.isTrue(isCancelled);
+  });
+});
+
+describe('cx.task', function() {
+  it('resolves a promise with the converted result', async function () {
+    assert.strictEqual(await addon.task_promise(10), 3628800);
+  });
+
+  it('rejects the promise if the conversion throws', async function () {
+    try {
+      await addon.task_promise_throw();
+      assert.fail('expected the promise to reject');
+    } catch (err) {
+      assert.instanceOf(err, TypeError);
+      assert.strictEqual(err.message, 'computed then threw');
+    }
+  });
+
+  it('rejects the promise if the task panics', async function () {
+    try {
+      await addon.task_promise_panic_execute();
+      assert.fail('expected the promise to reject');
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.match(err.message, /execute panicked/);
+    }
+  });
+
+  it('rejects the promise if the conversion panics', async function () {
+    try {
+      await addon.task_promise_panic_complete();
+      assert.fail('expected the promise to reject');
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.match(err.message, /complete panicked/);
+    }
+  });
+
+  it('rejects the promise if complete returns Throw without throwing', async function () {
+    try {
+      await addon.task_promise_unexpected_throw();
+      assert.fail('expected the promise to reject');
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.match(err.message, /without a pending exception/);
+    }
+  });
+});
@@ -71,4 +71,159 @@ const assert = require('chai').assert;
     // Asynchronously GC to give the task queue a chance to execute
     setTimeout(() => global.gc(), 10);
   });
+
+  // Replaces the test runner's `uncaughtException` listeners until an exception is caught
+  function onUncaughtException(listener) {
+    const listeners = process.listeners('uncaughtException');
+
+    process.removeAllListeners('uncaughtException');
+    process.once('uncaughtException', (err) => {
+      listeners.forEach((l) => process.on('uncaughtException', l));
+      listener(err);
+    });
+  }
+
+  it('should report exceptions thrown in a channel closure as uncaught', function (cb) {
+    onUncaughtException((err) => {
+      try {
+        assert.instanceOf(err, Error);
+        assert.strictEqual(err.message, 'Channel closure failed');
+        cb();
+      } catch (e) {
+        cb(e);
+      }
+    });
+
+    addon.channel_throw('Channel closure failed');
+  });
+
+  it('should pass exceptions thrown in a channel closure to the error handler', function (cb) {
+    addon.channel_throw_with_handler('Handled', (err) => {
+      try {
+        assert.instanceOf(err, Error);
+        assert.strictEqual(err.message, 'Handled');
+        cb();
+      } catch (e) {
+        cb(e);
+      }
+    });
+  });
+
+  it('should report exceptions thrown by the error handler as uncaught', function (cb) {
+    onUncaughtException((err) => {
+      try {
+        assert.strictEqual(err, 'Handler failed');
+        cb();
+      } catch (e) {
+        cb(e);
+      }
+    });
+
+    addon.channel_throw_with_handler('Unhandled', () => {
+      throw 'Handler failed';
+    });
+  });
+
+  it('should join the result of a channel closure', function (cb) {
+    addon.channel_join(() => 42, (err, n) => {
+      try {
+        assert.isNull(err);
+        assert.strictEqual(n, 42);
+        cb();
+      } catch (e) {
+        cb(e);
+      }
+    });
+  });
+
+  it('should join a channel closure that throws', function (cb) {
+    addon.channel_join(() => { throw new Error('failed'); }, (err) => {
+      try {
+        assert.strictEqual(err, 'Channel closure threw an exception');
+        cb();
+      } catch (e) {
+        cb(e);
+      }
+    });
+  });
+
+  it('should await the result of a channel closure', function (cb) {
+    addon.channel_join_future(() => 7, (err, n) => {
+      try {
+        assert.isNull(err);
+        assert.strictEqual(n, 7);
+        cb();
+      } catch (e) {
+        cb(e);
+      }
+    });
+  });
+
+  it('should fail to send on a full bounded channel', function (cb) {
+    const received = [];
+    const [sent, full] = addon.channel_bounded_try_send(2, 5, (i) => {
+      received.push(i);
+
+      if (received.length === 2) {
+        try {
+          assert.deepEqual(received, [0, 1]);
+          cb();
+        } catch (e) {
+          cb(e);
+        }
+      }
+    });
+
+    assert.strictEqual(sent, 2);
+    assert.strictEqual(full, 3);
+  });
+
+  function expectStream(n, cb) {
+    let next = 0;
+
+    return (i) => {
+      if (i !== next) {
+        return cb(new Error(`Expected ${next}, received ${i}`));
+      }
+
+      next += 1;
+
+      if (next === n) {
+        cb();
+      }
+    };
+  }
+
+  it('should block sending on a full bounded channel', function (cb) {
+    addon.channel_bounded_stream(100, false, expectStream(100, cb));
+  });
+
+  it('should retry sending on a full bounded channel', function (cb) {
+    addon.channel_bounded_stream(100, true, expectStream(100, cb));
+  });
+
+  it('should not block dropping a full bounded channel on the JavaScript thread', function (cb) {
+    addon.channel_bounded_drop_full(cb);
+  });
+
+  it('should unref a channel dropped while the bounded queue is full', function (done) {
+    const { Worker } = require('worker_threads');
+    const worker = new Worker(`
+      const addon = require(${JSON.stringify(require.resolve('..'))});
+
+      addon.channel_bounded_unref_full();
+    `, { eval: true });
+
+    // The worker only exits by itself if the channel no longer keeps its event loop alive
+    worker.once('error', done);
+    worker.once('exit', () => done());
+  });
+
+  it('should panic instead of blocking on the JavaScript thread', function () {
+    assert.throws(() => addon.channel_bounded_send_full(), /queue is full.*try_send/);
+  });
+
+  it('should panic instead of blocking a joinable send on the JavaScript thread', function () {
+    assert.throws(() => addon.channel_bounded_send_joinable_full(), /queue is full.*try_send/);
+  });
 });
@@ -0,0 +1,190 @@
+var addon = require('..');
+var assert = require('chai').assert;
+
+describe('JsTypedArray', function() {
+  it('should borrow the elements of a view over part of a buffer', function () {
+    const buf = new ArrayBuffer(32);
+    new Float32Array(buf).set([100, 1, 2, 3, 100, 100, 100, 100]);
+    const view = new Float32Array(buf, 4, 3);
+    assert.strictEqual(addon.sum_f32_array(view), 6);
+  });
+
+  it('should mutably borrow the elements of a view', function () {
+    const buf = new ArrayBuffer(32);
+    const all = new Float64Array(buf);
+    all.set([1, 2, 3, 4]);
+    addon.double_f64_array(new Float64Array(buf, 8, 2));
+    assert.deepEqual(Array.from(all), [1, 4, 6, 4]);
+  });
+
+  it('should report the layout of a view', function () {
+    const buf = new ArrayBuffer(16);
+    const info = addon.get_typed_array_info(new Uint16Array(buf, 6, 4));
+    assert.strictEqual(info.length, 4);
+    assert.strictEqual(info.byteOffset, 6);
+    assert.strictEqual(info.byteLength, 8);
+    assert.strictEqual(info.buffer, buf);
+  });
+
+  it('should create a zero-filled array', function () {
+    const array = addon.create_i32_array(5);
+    assert.instanceOf(array, Int32Array);
+    assert.deepEqual(Array.from(array), [0, 0, 0, 0, 0]);
+  });
+
+  it('should create arrays from slices of every element kind', function () {
+    const u8 = addon.create_u8_array_from_slice();
+    assert.instanceOf(u8, Uint8Array);
+    assert.deepEqual(Array.from(u8), [1, 2, 3, 4]);
+
+    const clamped = addon.create_clamped_array_from_slice();
+    assert.instanceOf(clamped, Uint8ClampedArray);
+    assert.deepEqual(Array.from(clamped), [0, 255]);
+
+    const i64 = addon.create_i64_array_from_slice();
+    assert.instanceOf(i64, BigInt64Array);
+    assert.deepEqual(Array.from(i64), [-(2n ** 63n), -1n, 2n ** 63n - 1n]);
+
+    const u64 = addon.create_u64_array_from_slice();
+    assert.instanceOf(u64, BigUint64Array);
+    assert.deepEqual(Array.from(u64), [0n, 2n ** 64n - 1n]);
+  });
+
+  it('should create a view over a region of a buffer', function () {
+    const buf = new ArrayBuffer(16);
+    const view = addon.create_f32_array_from_region(buf, 8, 2);
+    assert.instanceOf(view, Float32Array);
+    assert.strictEqual(view.buffer, buf);
+    assert.strictEqual(view.byteOffset, 8);
+    assert.strictEqual(view.length, 2);
+  });
+
+  it('should throw when a region is misaligned or out of bounds', function () {
+    const buf = new ArrayBuffer(16);
+    assert.throws(() => addon.create_f32_array_from_region(buf, 2, 1), RangeError);
+    assert.throws(() => addon.create_f32_array_from_region(buf, 8, 3), RangeError);
+  });
+
+  it('should copy a view into a Vec', function () {
+    const buf = new ArrayBuffer(16);
+    new Uint32Array(buf).set([1000, 1, 2, 1000]);
+    assert.strictEqual(addon.u32_array_to_vec_sum(new Uint32Array(buf, 4, 2)), 3);
+  });
+
+  it('should check the element kind of a typed array', function () {
+    const checks = {
+      is_i8_array: Int8Array,
+      is_u8_array: Uint8Array,
+      is_clamped_array: Uint8ClampedArray,
+      is_i16_array: Int16Array,
+      is_u16_array: Uint16Array,
+      is_i32_array: Int32Array,
+      is_u32_array: Uint32Array,
+      is_f32_array: Float32Array,
+      is_f64_array: Float64Array,
+      is_i64_array: BigInt64Array,
+      is_u64_array: BigUint64Array
+    };
+
+    for (const [check, Expected] of Object.entries(checks)) {
+      for (const Ctor of Object.values(checks)) {
+        assert.strictEqual(addon[check](new Ctor(4)), Ctor === Expected, `${check}(${Ctor.name})`);
+      }
+
+      assert.isFalse(addon[check](new ArrayBuffer(4)));
+      assert.isFalse(addon[check]([1, 2, 3]));
+      assert.isFalse(addon[check](new DataView(new ArrayBuffer(4))));
+    }
+
+    assert.isTrue(addon.is_u8_array(Buffer.from('hello')));
+  });
+});
+
+describe('JsDataView', function() {
+  const KINDS = {
+    Int8: [1, -128],
+    Uint8: [1, 255],
+    Int16: [2, -12345],
+    Uint16: [2, 54321],
+    Int32: [4, -123456789],
+    Uint32: [4, 3456789012],
+    Float32: [4, 1.5],
+    Float64: [8, -1234.5678],
+    BigInt64: [8, -(2n ** 62n) - 3n],
+    BigUint64: [8, 2n ** 64n - 3n]
+  };
+
+  it('should report the layout of a view', function () {
+    const buf = new ArrayBuffer(16);
+    const info = addon.get_data_view_info(new DataView(buf, 3, 10));
+    assert.strictEqual(info.byteLength, 10);
+    assert.strictEqual(info.byteOffset, 3);
+    assert.strictEqual(info.borrowedLength, 10);
+    assert.strictEqual(info.buffer, buf);
+  });
+
+  it('should create views', function () {
+    const view = addon.create_data_view(12);
+    assert.instanceOf(view, DataView);
+    assert.strictEqual(view.byteLength, 12);
+
+    const buf = new ArrayBuffer(8);
+    const whole = addon.create_data_view_from_buffer(buf);
+    assert.strictEqual(whole.buffer, buf);
+    assert.strictEqual(whole.byteLength, 8);
+
+    const region = addon.create_data_view_from_region(buf, 2, 4);
+    assert.strictEqual(region.buffer, buf);
+    assert.strictEqual(region.byteOffset, 2);
+    assert.strictEqual(region.byteLength, 4);
+
+    assert.throws(() => addon.create_data_view_from_region(buf, 6, 4), RangeError);
+  });
+
+  it('should read every width in both byte orders', function () {
+    const buf = new ArrayBuffer(32);
+    const view = new DataView(buf, 5, 20);
+
+    for (const [kind, [, value]] of Object.entries(KINDS)) {
+      for (const le of [true, false]) {
+        view[`set${kind}`](3, value, le);
+        assert.strictEqual(addon.read_data_view(view, kind, 3, le), view[`get${kind}`](3, le), `${kind} ${le}`);
+        assert.strictEqual(addon.read_data_view(view, kind, 3, le), value, `${kind} ${le}`);
+      }
+    }
+  });
+
+  it('should write every width in both byte orders', function () {
+    const buf = new ArrayBuffer(32);
+    const view = new DataView(buf, 7, 16);
+
+    for (const [kind, [, value]] of Object.entries(KINDS)) {
+      for (const le of [true, false]) {
+        new Uint8Array(buf).fill(0);
+        assert.isTrue(addon.write_data_view(view, kind, 2, le, value));
+        assert.strictEqual(view[`get${kind}`](2, le), value, `${kind} ${le}`);
+        assert.isTrue(new Uint8Array(buf, 0, 9).every(b => b === 0), `${kind} ${le} wrote before the view`);
+      }
+    }
+  });
+
+  it('should not read or write past the end of a view', function () {
+    const buf = new ArrayBuffer(32);
+    const view = new DataView(buf, 4, 8);
+
+    for (const [kind, [size, value]] of Object.entries(KINDS)) {
+      assert.isUndefined(addon.read_data_view(view, kind, 9 - size, true), kind);
+      assert.isFalse(addon.write_data_view(view, kind, 9 - size, true, value), kind);
+      assert.notStrictEqual(addon.read_data_view(view, kind, 8 - size, true), undefined, kind);
+    }
+
+    assert.isUndefined(addon.read_data_view(view, 'Uint8', 100, true));
+    assert.isTrue(new Uint8Array(buf).every(b => b === 0));
+  });
+
+  it('should check the type of a DataView', function () {
+    assert.isTrue(addon.is_data_view(new DataView(new ArrayBuffer(4))));
+    assert.isFalse(addon.is_data_view(new Uint8Array(4)));
+    assert.isFalse(addon.is_data_view(new ArrayBuffer(4)));
+  });
+});
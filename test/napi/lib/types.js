@@ -85,4 +85,56 @@ describe('type checks', function() {
     assert(!addon.strict_equals(o1, o2));
     assert(!addon.strict_equals(o1, 17));
   });
+
+  it('instance_of', function () {
+    class MyEvent {}
+    class MyOtherEvent {}
+    class MyDerivedEvent extends MyEvent {}
+
+    assert(addon.instance_of(new MyEvent(), MyEvent));
+    assert(addon.instance_of(new MyDerivedEvent(), MyEvent));
+    assert(addon.instance_of(new MyEvent(), Object));
+    assert(addon.instance_of([], Array));
+    assert(!addon.instance_of(new MyEvent(), MyOtherEvent));
+    assert(!addon.instance_of({}, MyEvent));
+    assert(!addon.instance_of(17, Number));
+    assert(!addon.instance_of(null, Object));
+  });
+
+  it('instance_of uses Symbol.hasInstance', function () {
+    function Even() {}
+    Object.defineProperty(Even, Symbol.hasInstance, {
+      value(n) { return n % 2 === 0; }
+    });
+
+    function Throws() {}
+    Object.defineProperty(Throws, Symbol.hasInstance, {
+      value() { throw new Error('hasInstance threw'); }
+    });
+
+    assert(addon.instance_of(2, Even));
+    assert(!addon.instance_of(3, Even));
+    assert.throws(() => addon.instance_of({}, Throws), /hasInstance threw/);
+  });
+
+  it('downcast_instance_or_throw', function () {
+    class MyEvent {
+      constructor(type) {
+        this.type = type;
+      }
+    }
+
+    assert.strictEqual(addon.get_event_type(new MyEvent('click'), MyEvent), 'click');
+    assert.throws(() => addon.get_event_type({ type: 'click' }, MyEvent), TypeError, /expected an instance of MyEvent/);
+    assert.throws(() => addon.get_event_type('click', MyEvent), TypeError, /expected an instance of MyEvent/);
+    assert.throws(() => addon.get_event_type({}, function () {}), TypeError, /expected an instance of the constructor/);
+
+    class AnyString {
+      static [Symbol.hasInstance](value) {
+        return typeof value === 'string';
+      }
+    }
+
+    assert.throws(() => addon.get_event_type('click', AnyString), TypeError, /expected an instance of AnyString/);
+  });
 });
@@ -0,0 +1,87 @@
+use neon::prelude::*;
+use neon::types::bigint::Sign;
+use neon::types::JsBigInt;
+
+macro_rules! roundtrip {
+    ($name:ident, $lossy:ident, $to:ident, $from:ident) => {
+        pub fn $name(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+            let n = cx.argument::<JsBigInt>(0)?;
+
+            match n.$to(&mut cx) {
+                Ok(n) => Ok(JsBigInt::$from(&mut cx, n)),
+                Err(err) => cx.throw_range_error(err.to_string()),
+            }
+        }
+
+        pub fn $lossy(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+            let n = cx.argument::<JsBigInt>(0)?;
+            let n = n.$to(&mut cx).unwrap_or_else(|err| err.into_inner());
+
+            Ok(JsBigInt::$from(&mut cx, n))
+        }
+    };
+}
+
+roundtrip!(bigint_i64_roundtrip, bigint_i64_lossy, to_i64, from_i64);
+roundtrip!(bigint_u64_roundtrip, bigint_u64_lossy, to_u64, from_u64);
+roundtrip!(bigint_i128_roundtrip, bigint_i128_lossy, to_i128, from_i128);
+roundtrip!(bigint_u128_roundtrip, bigint_u128_lossy, to_u128, from_u128);
+
+pub fn bigint_to_words(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let n = cx.argument::<JsBigInt>(0)?;
+    let (sign, words) = n.to_words(&mut cx);
+    let result = cx.empty_object();
+    let negative = cx.boolean(sign == Sign::Negative);
+    let array = cx.empty_array();
+
+    for (i, word) in words.into_iter().enumerate() {
+        let word = JsBigInt::from_u64(&mut cx, word);
+        array.set(&mut cx, i as u32, word)?;
+    }
+
+    result.set(&mut cx, "negative", negative)?;
+    result.set(&mut cx, "words", array)?;
+
+    Ok(result)
+}
+
+pub fn bigint_from_words(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    let sign = if cx.argument::<JsBoolean>(0)?.value(&mut cx) {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+
+    let words = cx
+        .argument::<JsArray>(1)?
+        .to_vec(&mut cx)?
+        .into_iter()
+        .map(|word| {
+            let word = word.downcast_or_throw::<JsBigInt, _>(&mut cx)?;
+            word.to_u64(&mut cx)
+                .or_else(|err| cx.throw_range_error(err.to_string()))
+        })
+        .collect::<NeonResult<Vec<_>>>()?;
+
+    JsBigInt::from_words(&mut cx, sign, &words)
+}
+
+pub fn bigint_read_low_word(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let n = cx.argument::<JsBigInt>(0)?;
+    let mut words = [0u64; 1];
+    let (_, len) = n.read_words(&mut cx, &mut words);
+    let result = cx.empty_array();
+    let word = JsBigInt::from_u64(&mut cx, words[0]);
+    let len = cx.number(len as f64);
+
+    result.set(&mut cx, 0, word)?;
+    result.set(&mut cx, 1, len)?;
+
+    Ok(result)
+}
+
+pub fn is_bigint(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let is_bigint = cx.argument::<JsValue>(0)?.is_a::<JsBigInt, _>(&mut cx);
+
+    Ok(cx.boolean(is_bigint))
+}
@@ -0,0 +1,88 @@
+use neon::prelude::*;
+
+pub struct User {
+    name: String,
+}
+
+impl Finalize for User {}
+
+#[neon::class]
+impl User {
+    #[neon(constructor)]
+    fn new(mut cx: FunctionContext) -> NeonResult<Self> {
+        let name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+        Ok(User { name })
+    }
+
+    fn greet<'a>(&self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsString> {
+        Ok(cx.string(format!("Hello, {}!", self.name)))
+    }
+
+    #[neon(name = "rename")]
+    fn rename_user<'a>(&mut self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsUndefined> {
+        self.name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+        Ok(cx.undefined())
+    }
+
+    #[neon(getter)]
+    fn name<'a>(&self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsString> {
+        Ok(cx.string(&self.name))
+    }
+
+    #[neon(setter)]
+    fn set_name<'a>(&mut self, mut cx: FunctionContext<'a>) -> NeonResult<()> {
+        self.name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+        Ok(())
+    }
+
+    #[neon(getter)]
+    fn shout<'a>(&self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsString> {
+        Ok(cx.string(self.name.to_uppercase()))
+    }
+
+    fn fail<'a>(&self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsUndefined> {
+        cx.throw_error("User failed")
+    }
+
+    fn default_name(mut cx: FunctionContext) -> JsResult<JsString> {
+        Ok(cx.string(User::anonymous()))
+    }
+
+    #[neon(skip)]
+    fn anonymous() -> &'static str {
+        "Anonymous"
+    }
+}
+
+pub struct Counter {
+    count: f64,
+}
+
+impl Finalize for Counter {}
+
+#[neon::class]
+impl Counter {
+    #[neon(constructor)]
+    fn new(_cx: FunctionContext) -> NeonResult<Self> {
+        Ok(Counter { count: 0.0 })
+    }
+
+    fn increment<'a>(&mut self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsNumber> {
+        self.count += 1.0;
+
+        Ok(cx.number(self.count))
+    }
+
+    // Calls back into JavaScript while `self` is mutably borrowed
+    fn increment_with<'a>(&mut self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsValue> {
+        let f = cx.argument::<JsFunction>(0)?;
+        let this = cx.this();
+        let args: Vec<Handle<JsValue>> = vec![];
+
+        self.count += 1.0;
+        f.call(&mut cx, this, args)
+    }
+}
@@ -4,3 +4,18 @@ pub fn to_string(mut cx: FunctionContext) -> JsResult<JsString> {
     let arg: Handle<JsValue> = cx.argument(0)?;
     arg.to_string(&mut cx)
 }
+
+pub fn to_number(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let arg: Handle<JsValue> = cx.argument(0)?;
+    arg.to_number(&mut cx)
+}
+
+pub fn to_boolean(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let arg: Handle<JsValue> = cx.argument(0)?;
+    Ok(arg.to_boolean(&mut cx))
+}
+
+pub fn to_object(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let arg: Handle<JsValue> = cx.argument(0)?;
+    arg.to_object(&mut cx)
+}
@@ -1,4 +1,8 @@
+use std::convert::TryFrom;
+
 use neon::prelude::*;
+use neon::result::{CaughtError, ResultExt};
+use neon::types::ErrorKind;
 
 pub fn new_error(mut cx: FunctionContext) -> JsResult<JsError> {
     let msg = cx.argument::<JsString>(0)?.value(&mut cx);
@@ -32,3 +36,191 @@ pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
         panic!()
     }
 }
+
+pub fn new_error_with_code(mut cx: FunctionContext) -> JsResult<JsError> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let code = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    JsError::builder(msg)
+        .kind(ErrorKind::TypeError)
+        .code(code)
+        .build(&mut cx)
+}
+
+pub fn new_error_with_cause(mut cx: FunctionContext) -> JsResult<JsError> {
+    let cause = cx.argument::<JsValue>(0)?;
+
+    JsError::builder("Failed to load config")
+        .cause(cause)
+        .build(&mut cx)
+}
+
+pub fn new_error_with_properties(mut cx: FunctionContext) -> JsResult<JsError> {
+    let path = cx.string("/etc/app.toml");
+    let line = cx.number(3);
+
+    JsError::builder("Invalid config")
+        .kind(ErrorKind::RangeError)
+        .name("ConfigError")
+        .code("ERR_CONFIG")
+        .property("path", path)
+        .property("line", line)
+        .build(&mut cx)
+}
+
+pub fn throw_error_with_code(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let code = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsError::builder("Oh, no!").code(code).throw(&mut cx)
+}
+
+pub fn read_file_or_throw(mut cx: FunctionContext) -> JsResult<JsString> {
+    let path = cx.argument::<JsString>(0)?.value(&mut cx);
+    let contents = std::fs::read_to_string(path).or_throw(&mut cx)?;
+
+    Ok(cx.string(contents))
+}
+
+pub fn parse_u8_or_throw(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as i64;
+    let n = u8::try_from(n).or_throw_js(&mut cx)?;
+
+    Ok(cx.number(n))
+}
+
+#[derive(Debug)]
+struct LoadError {
+    source: std::num::ParseIntError,
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Failed to load settings")
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl IntoJsError for LoadError {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::TypeError
+    }
+
+    fn code(&self) -> Option<String> {
+        Some("ERR_LOAD_SETTINGS".to_string())
+    }
+}
+
+pub fn load_settings_or_throw(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let input = cx.argument::<JsString>(0)?.value(&mut cx);
+    let n = input
+        .parse::<i32>()
+        .map_err(|source| LoadError { source })
+        .or_throw_js(&mut cx)?;
+
+    Ok(cx.number(n))
+}
+
+pub fn load_settings_or_throw_default(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let input = cx.argument::<JsString>(0)?.value(&mut cx);
+    let n = input
+        .parse::<i32>()
+        .map_err(|source| LoadError { source })
+        .or_throw(&mut cx)?;
+
+    Ok(cx.number(n))
+}
+
+/// An error that is its own source
+#[derive(Debug)]
+struct CyclicError;
+
+impl std::fmt::Display for CyclicError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Cyclic error")
+    }
+}
+
+impl std::error::Error for CyclicError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self)
+    }
+}
+
+impl IntoJsError for CyclicError {}
+
+pub fn cyclic_error_or_throw(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    Err(CyclicError).or_throw(&mut cx)
+}
+
+pub fn cyclic_error_or_throw_js(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    Err(CyclicError).or_throw_js(&mut cx)
+}
+
+fn describe_caught_error<'a>(
+    cx: &mut FunctionContext<'a>,
+    err: &CaughtError,
+) -> JsResult<'a, JsObject> {
+    let obj = cx.empty_object();
+    let display = cx.string(err.to_string());
+    let message = cx.string(err.message());
+
+    obj.set(cx, "display", display)?;
+    obj.set(cx, "message", message)?;
+
+    if let Some(name) = err.name() {
+        let name = cx.string(name);
+        obj.set(cx, "name", name)?;
+    }
+
+    if let Some(code) = err.code() {
+        let code = cx.string(code);
+        obj.set(cx, "code", code)?;
+    }
+
+    let has_stack = cx.boolean(err.stack().is_some());
+    obj.set(cx, "hasStack", has_stack)?;
+
+    if let Some(cause) = err.cause() {
+        let cause = describe_caught_error(cx, cause)?;
+        obj.set(cx, "cause", cause)?;
+    }
+
+    Ok(obj)
+}
+
+pub fn catch_error_details(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let result = cx.try_catch(|cx| {
+        let null = cx.null();
+        f.call(cx, null, Vec::<Handle<JsValue>>::new())
+    });
+
+    let exception = match result {
+        Ok(_) => return Ok(cx.undefined().upcast()),
+        Err(exception) => exception,
+    };
+
+    let err = CaughtError::new(&mut cx, exception)?;
+
+    Ok(describe_caught_error(&mut cx, &err)?.upcast())
+}
+
+pub fn count_caught_error_sources(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let exception = cx.argument::<JsValue>(0)?;
+    let err: Box<dyn std::error::Error + Send + Sync> =
+        Box::new(CaughtError::new(&mut cx, exception)?);
+    let mut count = 0;
+    let mut source = err.source();
+
+    while let Some(err) = source {
+        count += 1;
+        source = err.source();
+    }
+
+    Ok(cx.number(count))
+}
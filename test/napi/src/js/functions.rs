@@ -1,5 +1,9 @@
 use neon::object::This;
 use neon::prelude::*;
+use neon::result::Throw;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 fn add1(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let x = cx.argument::<JsNumber>(0)?.value(&mut cx);
@@ -147,3 +151,123 @@ pub fn is_construct(mut cx: FunctionContext) -> JsResult<JsObject> {
     this.set(&mut cx, "wasConstructed", construct)?;
     Ok(this)
 }
+
+pub fn make_counter(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let start = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let count = Cell::new(start);
+
+    JsFunction::from_closure(&mut cx, move |mut cx| {
+        let n = count.get() + 1.0;
+        count.set(n);
+        Ok(cx.number(n))
+    })
+}
+
+pub fn make_throwing_closure(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let message = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsFunction::from_closure(&mut cx, move |mut cx| -> JsResult<JsValue> {
+        cx.throw_error(&message)
+    })
+}
+
+pub fn make_panicking_closure(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let message = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsFunction::from_closure(&mut cx, move |_: FunctionContext| -> JsResult<JsValue> {
+        panic!("{}", message)
+    })
+}
+
+static CLOSURES_DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+struct DropCounter;
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        CLOSURES_DROPPED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+pub fn make_dropping_closure(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let counter = DropCounter;
+
+    JsFunction::from_closure(&mut cx, move |mut cx| {
+        let _ = &counter;
+        Ok(cx.undefined())
+    })
+}
+
+pub fn count_dropped_closures(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(CLOSURES_DROPPED.load(Ordering::SeqCst) as f64))
+}
+
+fn call_with_null<'a, C: Context<'a>>(cx: &mut C, f: Handle<JsFunction>) -> JsResult<'a, JsValue> {
+    let null = cx.null();
+    f.call(cx, null, Vec::<Handle<JsValue>>::new())
+}
+
+pub fn nested_try_catch(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let inner: Handle<JsFunction> = cx.argument(0)?;
+    let outer: Handle<JsFunction> = cx.argument(1)?;
+    let caught = cx.empty_array();
+    let result = cx.try_catch(|cx| {
+        if let Err(err) = cx.try_catch(|cx| call_with_null(cx, inner)) {
+            caught.set(cx, 0, err)?;
+        }
+
+        call_with_null(cx, outer)
+    });
+
+    if let Err(err) = result {
+        caught.set(&mut cx, 1, err)?;
+    }
+
+    Ok(caught)
+}
+
+pub fn try_catch_without_throwing(mut cx: FunctionContext) -> JsResult<JsValue> {
+    Ok(cx
+        .try_catch(|_| -> NeonResult<()> { Err(Throw) })
+        .unwrap_err())
+}
+
+pub fn try_catch_after_panic(mut cx: FunctionContext) -> JsResult<JsString> {
+    let f: Handle<JsFunction> = cx.argument(0)?;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        cx.try_catch(|cx| -> NeonResult<()> {
+            let _ = call_with_null(cx, f);
+            panic!("panicked with a pending exception");
+        })
+    }));
+
+    assert!(result.is_err());
+
+    Ok(cx.string("recovered"))
+}
+
+pub fn try_catch_finally(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f: Handle<JsFunction> = cx.argument(0)?;
+    let finally: Handle<JsFunction> = cx.argument(1)?;
+
+    Ok(cx
+        .try_catch_finally(
+            |cx| call_with_null(cx, f),
+            |cx| call_with_null(cx, finally).map(|_| ()),
+        )
+        .unwrap_or_else(|err| err))
+}
+
+pub fn try_catch_finally_after_panic(mut cx: FunctionContext) -> JsResult<JsString> {
+    let finally: Handle<JsFunction> = cx.argument(0)?;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        cx.try_catch_finally(
+            |_| -> NeonResult<()> { panic!("panicked before finally") },
+            |cx| call_with_null(cx, finally).map(|_| ()),
+        )
+    }));
+
+    assert!(result.is_err());
+
+    Ok(cx.string("recovered"))
+}
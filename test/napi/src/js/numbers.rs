@@ -39,3 +39,50 @@ pub fn accept_and_return_negative_js_number(mut cx: FunctionContext) -> JsResult
     let number: Handle<JsNumber> = cx.argument(0)?;
     Ok(number)
 }
+
+pub fn create_integers(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let values = [
+        JsNumber::from_i32(&mut cx, i32::MIN),
+        JsNumber::from_u32(&mut cx, u32::MAX),
+        JsNumber::from_i64(&mut cx, -9007199254740991),
+        JsNumber::from_i64(&mut cx, i64::MAX),
+    ];
+    let array = JsArray::new(&mut cx, values.len() as u32);
+
+    for (i, value) in values.iter().enumerate() {
+        array.set(&mut cx, i as u32, *value)?;
+    }
+
+    Ok(array)
+}
+
+pub fn number_value_i32(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_i32(&mut cx);
+    Ok(JsNumber::from_i32(&mut cx, n))
+}
+
+pub fn number_value_u32(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_u32(&mut cx);
+    Ok(JsNumber::from_u32(&mut cx, n))
+}
+
+// Returns the `i64` as a string, since it may not be representable as a number
+pub fn number_value_i64(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx.argument::<JsNumber>(0)?.value_i64(&mut cx);
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn number_value_i32_or_throw(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_i32_or_throw(&mut cx)?;
+    Ok(JsNumber::from_i32(&mut cx, n))
+}
+
+pub fn number_value_u32_or_throw(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_u32_or_throw(&mut cx)?;
+    Ok(JsNumber::from_u32(&mut cx, n))
+}
+
+pub fn number_value_i64_or_throw(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx.argument::<JsNumber>(0)?.value_i64_or_throw(&mut cx)?;
+    Ok(cx.string(n.to_string()))
+}
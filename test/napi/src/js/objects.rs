@@ -1,4 +1,8 @@
+use neon::object::{KeyCollectionMode, KeyConversion, KeyOptions, PropertyDescriptor, PropertyKey};
 use neon::prelude::*;
+use neon::types::JsSymbol;
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub fn return_js_global_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.global())
@@ -171,3 +175,298 @@ pub fn increment_buffer_with_borrow_mut(mut cx: FunctionContext) -> JsResult<JsU
     });
     Ok(cx.undefined())
 }
+
+pub struct WrappedCounter {
+    count: Cell<u32>,
+}
+
+impl Finalize for WrappedCounter {
+    fn finalize<'a, C: Context<'a>>(self, _: &mut C) {
+        COUNTERS_FINALIZED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+static COUNTERS_FINALIZED: AtomicUsize = AtomicUsize::new(0);
+
+pub fn wrap_counter(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let count = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+
+    obj.wrap(
+        &mut cx,
+        WrappedCounter {
+            count: Cell::new(count),
+        },
+    )?;
+
+    Ok(cx.undefined())
+}
+
+pub fn increment_counter(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let this = cx.this();
+    let counter = match this.unwrap::<WrappedCounter, _>(&mut cx) {
+        Ok(counter) => counter,
+        Err(err) => return cx.throw_type_error(err.to_string()),
+    };
+
+    counter.count.set(counter.count.get() + 1);
+
+    Ok(cx.number(counter.count.get()))
+}
+
+pub fn is_counter(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let is_counter = obj.unwrap::<WrappedCounter, _>(&mut cx).is_ok();
+
+    Ok(cx.boolean(is_counter))
+}
+
+pub fn remove_counter(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    // Safety: No references from `unwrap` are alive
+    match unsafe { obj.remove_wrap::<WrappedCounter, _>(&mut cx) } {
+        Some(counter) => Ok(cx.number(counter.count.get()).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn wrap_string(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let s = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    obj.wrap(&mut cx, s)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn count_finalized_counters(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(COUNTERS_FINALIZED.load(Ordering::SeqCst) as f64))
+}
+
+pub fn define_constants(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.empty_object();
+    let answer = cx.number(42);
+    let hidden = cx.string("hidden");
+    let first = cx.string("first");
+    let symbol = cx.argument::<JsSymbol>(0)?;
+    let tagged = cx.boolean(true);
+
+    obj.define_properties(
+        &mut cx,
+        vec![
+            ("ANSWER", PropertyDescriptor::value(answer).enumerable(true)),
+            ("HIDDEN", PropertyDescriptor::value(hidden)),
+        ],
+    )?;
+
+    obj.define_property(&mut cx, 0, PropertyDescriptor::value(first).writable(true))?;
+    obj.define_property(
+        &mut cx,
+        symbol,
+        PropertyDescriptor::value(tagged).configurable(true),
+    )?;
+
+    Ok(obj)
+}
+
+pub fn define_property_value(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let value = cx.argument::<JsValue>(2)?;
+
+    obj.define_property(&mut cx, key, PropertyDescriptor::value(value))?;
+
+    Ok(cx.undefined())
+}
+
+pub struct Temperature {
+    celsius: RefCell<f64>,
+}
+
+impl Finalize for Temperature {}
+
+// A `JsBox` is not extensible; accessors are defined on an object holding the box
+fn this_temperature<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsBox<Temperature>> {
+    let this = cx.this();
+
+    this.get(cx, "inner")?
+        .downcast_or_throw::<JsBox<Temperature>, _>(cx)
+}
+
+fn get_celsius(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let celsius = *this_temperature(&mut cx)?.celsius.borrow();
+
+    Ok(cx.number(celsius).upcast())
+}
+
+fn set_celsius(mut cx: FunctionContext) -> NeonResult<()> {
+    let celsius = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+    if celsius < -273.15 {
+        return cx.throw_range_error("Temperature below absolute zero");
+    }
+
+    *this_temperature(&mut cx)?.celsius.borrow_mut() = celsius;
+
+    Ok(())
+}
+
+fn get_fahrenheit(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let celsius = *this_temperature(&mut cx)?.celsius.borrow();
+
+    Ok(cx.number(celsius * 9.0 / 5.0 + 32.0).upcast())
+}
+
+fn get_panic(_: FunctionContext) -> JsResult<JsValue> {
+    panic!("getter panicked")
+}
+
+pub fn create_temperature(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let celsius = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let inner = cx.boxed(Temperature {
+        celsius: RefCell::new(celsius),
+    });
+    let temperature = cx.empty_object();
+
+    temperature.define_properties(
+        &mut cx,
+        vec![
+            ("inner", PropertyDescriptor::value(inner)),
+            (
+                "celsius",
+                PropertyDescriptor::accessor(Some(get_celsius), Some(set_celsius)).enumerable(true),
+            ),
+            (
+                "fahrenheit",
+                PropertyDescriptor::getter(get_fahrenheit).enumerable(true),
+            ),
+            ("panic", PropertyDescriptor::getter(get_panic)),
+        ],
+    )?;
+
+    Ok(temperature)
+}
+
+fn key_op<'a, K: PropertyKey>(
+    cx: &mut FunctionContext<'a>,
+    obj: Handle<'a, JsObject>,
+    op: &str,
+    key: K,
+) -> NeonResult<bool> {
+    match op {
+        "has" => obj.has(cx, key),
+        "has_own" => obj.has_own(cx, key),
+        "delete" => obj.delete(cx, key),
+        _ => cx.throw_error(format!("Unknown operation: {}", op)),
+    }
+}
+
+// Applies `op` to the key, passed to Rust as a `u32`, `&str` or handle
+pub fn object_key_op(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let op = cx.argument::<JsString>(1)?.value(&mut cx);
+    let key = cx.argument::<JsValue>(2)?;
+    let result = if let Ok(n) = key.downcast::<JsNumber, _>(&mut cx) {
+        let index = n.value(&mut cx) as u32;
+
+        key_op(&mut cx, obj, &op, index)?
+    } else if let Ok(s) = key.downcast::<JsString, _>(&mut cx) {
+        let s = s.value(&mut cx);
+
+        key_op(&mut cx, obj, &op, s.as_str())?
+    } else {
+        key_op(&mut cx, obj, &op, key)?
+    };
+
+    Ok(cx.boolean(result))
+}
+
+pub fn object_has_own_handle(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let result = obj.has_own(&mut cx, key)?;
+
+    Ok(cx.boolean(result))
+}
+
+pub fn object_get_prototype(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    obj.get_prototype(&mut cx)
+}
+
+pub fn object_freeze(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    obj.freeze(&mut cx)?;
+
+    Ok(obj)
+}
+
+pub fn object_seal(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    obj.seal(&mut cx)?;
+
+    Ok(obj)
+}
+
+// Reads `KeyOptions` from a JavaScript object of booleans
+fn key_options(cx: &mut FunctionContext, options: Handle<JsObject>) -> NeonResult<KeyOptions> {
+    let flag = |cx: &mut FunctionContext, name: &str| -> NeonResult<bool> {
+        let value = options.get(cx, name)?;
+
+        Ok(value
+            .downcast::<JsBoolean, _>(cx)
+            .map(|value| value.value(cx))
+            .unwrap_or(false))
+    };
+
+    let mode = if flag(cx, "includePrototypes")? {
+        KeyCollectionMode::IncludePrototypes
+    } else {
+        KeyCollectionMode::OwnOnly
+    };
+
+    let conversion = if flag(cx, "keepNumbers")? {
+        KeyConversion::KeepNumbers
+    } else {
+        KeyConversion::NumbersToStrings
+    };
+
+    Ok(KeyOptions::new()
+        .mode(mode)
+        .conversion(conversion)
+        .writable(flag(cx, "writable")?)
+        .enumerable(flag(cx, "enumerable")?)
+        .configurable(flag(cx, "configurable")?)
+        .skip_strings(flag(cx, "skipStrings")?)
+        .skip_symbols(flag(cx, "skipSymbols")?))
+}
+
+pub fn get_property_names(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let options = cx.argument::<JsObject>(1)?;
+    let options = key_options(&mut cx, options)?;
+
+    obj.get_property_names(&mut cx, options)
+}
+
+pub fn get_entries(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let options = cx.argument::<JsObject>(1)?;
+    let options = key_options(&mut cx, options)?;
+    let entries = obj.entries(&mut cx, options)?;
+    let result = JsArray::new(&mut cx, entries.len() as u32);
+
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        let entry = JsArray::new(&mut cx, 2);
+
+        entry.set(&mut cx, 0, key)?;
+        entry.set(&mut cx, 1, value)?;
+        result.set(&mut cx, i as u32, entry)?;
+    }
+
+    Ok(result)
+}
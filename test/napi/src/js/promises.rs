@@ -0,0 +1,141 @@
+use neon::prelude::*;
+use neon::result::Throw;
+
+pub fn resolve_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let value = cx.argument::<JsValue>(0)?;
+    let (deferred, promise) = cx.promise();
+
+    deferred.resolve(&mut cx, value);
+
+    Ok(promise)
+}
+
+pub fn reject_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let err = cx.argument::<JsValue>(0)?;
+    let (deferred, promise) = cx.promise();
+
+    deferred.reject(&mut cx, err);
+
+    Ok(promise)
+}
+
+pub fn is_promise(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value = cx.argument::<JsValue>(0)?;
+    let is_promise = value.is_a::<JsPromise, _>(&mut cx);
+
+    Ok(cx.boolean(is_promise))
+}
+
+pub fn settle_promise_from_thread(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    std::thread::spawn(move || {
+        let result = n * 2.0;
+
+        deferred.settle_with(&channel, move |mut cx| Ok(cx.number(result)));
+    });
+
+    Ok(promise)
+}
+
+pub fn reject_promise_from_thread(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    std::thread::spawn(move || {
+        deferred.settle_with(&channel, move |mut cx| -> JsResult<JsValue> {
+            cx.throw_error(msg)
+        });
+    });
+
+    Ok(promise)
+}
+
+pub fn settle_promise_unexpected_throw(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    deferred.settle_with(&channel, |_| -> JsResult<JsValue> { Err(Throw) });
+
+    Ok(promise)
+}
+
+pub fn retry_settle_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let channel = Channel::bounded(&mut cx, 1);
+    let (deferred, promise) = cx.promise();
+
+    // Fill the queue; it is not drained until this function returns
+    channel.send(|_| Ok(()));
+
+    let err = match deferred.try_settle_with(&channel, move |mut cx| Ok(cx.number(n * 2.0))) {
+        Ok(()) => return cx.throw_error("Expected the queue to be full"),
+        Err(err) => err,
+    };
+
+    if !err.is_full() {
+        return cx.throw_error(err.to_string());
+    }
+
+    std::thread::spawn(move || {
+        let (mut deferred, mut complete) = err.into_inner();
+
+        // Retry with the `Deferred` and closure returned in the error
+        while let Err(err) = deferred.try_settle_with(&channel, complete) {
+            assert!(err.is_full());
+
+            let (d, c) = err.into_inner();
+
+            deferred = d;
+            complete = c;
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+    });
+
+    Ok(promise)
+}
+
+pub fn leak_deferred(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let (deferred, promise) = cx.promise();
+
+    std::mem::drop(deferred);
+
+    Ok(promise)
+}
+
+pub fn spawn_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let runtime = neon::executor::handle(&mut cx)?;
+
+    cx.spawn_promise(
+        async move { runtime.spawn_blocking(move || n * 2.0).await.unwrap() },
+        |mut cx, n| Ok(cx.number(n)),
+    )
+}
+
+pub fn spawn_promise_throw(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.spawn_promise(async move { msg }, |mut cx, msg| {
+        cx.throw_error::<_, Handle<JsUndefined>>(msg)
+    })
+}
+
+pub fn spawn_promise_panic(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    cx.spawn_promise(
+        async {
+            panic!("spawned future panicked");
+        },
+        |mut cx, ()| Ok(cx.undefined()),
+    )
+}
+
+pub fn spawn_promise_pending(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    cx.spawn_promise(
+        std::future::pending::<()>(),
+        |mut cx, ()| Ok(cx.undefined()),
+    )
+}
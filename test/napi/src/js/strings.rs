@@ -1,5 +1,6 @@
 use neon::prelude::*;
 use neon::reflect::eval;
+use neon::types::JsTypedArray;
 
 pub fn return_js_string(mut cx: FunctionContext) -> JsResult<JsString> {
     Ok(cx.string("hello node"))
@@ -9,3 +10,57 @@ pub fn run_string_as_script(mut cx: FunctionContext) -> JsResult<JsValue> {
     let string_script = cx.argument::<JsString>(0)?;
     eval(&mut cx, string_script)
 }
+
+pub fn string_to_utf16(mut cx: FunctionContext) -> JsResult<JsTypedArray<u16>> {
+    let s = cx.argument::<JsString>(0)?;
+    let units = s.to_utf16(&mut cx);
+
+    JsTypedArray::from_slice(&mut cx, &units)
+}
+
+pub fn string_from_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    let units = cx.argument::<JsTypedArray<u16>>(0)?.to_vec(&mut cx);
+
+    JsString::from_utf16(&mut cx, &units).or_throw(&mut cx)
+}
+
+pub fn string_to_latin1(mut cx: FunctionContext) -> JsResult<JsTypedArray<u8>> {
+    let s = cx.argument::<JsString>(0)?;
+    let bytes = s.to_latin1(&mut cx);
+
+    JsTypedArray::from_slice(&mut cx, &bytes)
+}
+
+pub fn string_from_latin1(mut cx: FunctionContext) -> JsResult<JsString> {
+    let bytes = cx.argument::<JsTypedArray<u8>>(0)?.to_vec(&mut cx);
+
+    JsString::from_latin1(&mut cx, &bytes).or_throw(&mut cx)
+}
+
+pub fn string_utf16_len(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let s = cx.argument::<JsString>(0)?;
+    let len = s.utf16_len(&mut cx);
+
+    Ok(cx.number(len as f64))
+}
+
+// Reads the string into a fixed-size stack buffer and returns the code units read
+pub fn string_read_utf16(mut cx: FunctionContext) -> JsResult<JsTypedArray<u16>> {
+    let s = cx.argument::<JsString>(0)?;
+    let size = cx.argument::<JsNumber>(1)?.value_u32_or_throw(&mut cx)? as usize;
+    let mut buffer = [0u16; 8];
+    let size = size.min(buffer.len());
+    let len = s.read_utf16(&mut cx, &mut buffer[..size]);
+
+    JsTypedArray::from_slice(&mut cx, &buffer[..len])
+}
+
+pub fn string_read_latin1(mut cx: FunctionContext) -> JsResult<JsTypedArray<u8>> {
+    let s = cx.argument::<JsString>(0)?;
+    let size = cx.argument::<JsNumber>(1)?.value_u32_or_throw(&mut cx)? as usize;
+    let mut buffer = [0u8; 8];
+    let size = size.min(buffer.len());
+    let len = s.read_latin1(&mut cx, &mut buffer[..size]);
+
+    JsTypedArray::from_slice(&mut cx, &buffer[..len])
+}
@@ -0,0 +1,101 @@
+use neon::prelude::*;
+use neon::types::JsSymbol;
+
+pub fn create_symbol(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let description = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    Ok(JsSymbol::new(&mut cx, description))
+}
+
+pub fn create_symbol_without_description(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    Ok(JsSymbol::without_description(&mut cx))
+}
+
+pub fn get_symbol_description(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let symbol = cx.argument::<JsSymbol>(0)?;
+
+    match symbol.description(&mut cx)? {
+        Some(description) => Ok(cx.string(description).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn get_well_known_symbol(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsSymbol::well_known(&mut cx, &name)
+}
+
+pub fn get_registered_symbol(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let key = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsSymbol::for_key(&mut cx, key)
+}
+
+pub fn is_symbol(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let is_symbol = cx.argument::<JsValue>(0)?.is_a::<JsSymbol, _>(&mut cx);
+
+    Ok(cx.boolean(is_symbol))
+}
+
+pub fn get_symbol_property(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsSymbol>(1)?;
+
+    obj.get(&mut cx, key)
+}
+
+pub fn set_symbol_property(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsSymbol>(1)?;
+    let value = cx.argument::<JsValue>(2)?;
+    let result = obj.set(&mut cx, key, value)?;
+
+    Ok(cx.boolean(result))
+}
+
+fn iterator_next(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let this = cx.this();
+    let count = this
+        .get(&mut cx, "count")?
+        .downcast_or_throw::<JsNumber, _>(&mut cx)?
+        .value(&mut cx);
+    let result = cx.empty_object();
+    let done = cx.boolean(count >= 3.0);
+    let value = cx.number(count);
+    let next_count = cx.number(count + 1.0);
+
+    this.set(&mut cx, "count", next_count)?;
+    result.set(&mut cx, "done", done)?;
+    result.set(&mut cx, "value", value)?;
+
+    Ok(result)
+}
+
+fn iterator(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let iter = cx.empty_object();
+    let count = cx.number(0);
+    let next = JsFunction::new(&mut cx, iterator_next)?;
+
+    iter.set(&mut cx, "count", count)?;
+    iter.set(&mut cx, "next", next)?;
+
+    Ok(iter)
+}
+
+fn inspect(mut cx: FunctionContext) -> JsResult<JsString> {
+    Ok(cx.string("<counter>"))
+}
+
+pub fn create_counter(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.empty_object();
+    let iterator_key = JsSymbol::iterator(&mut cx)?;
+    let iterator = JsFunction::new(&mut cx, iterator)?;
+    let inspect_key = JsSymbol::for_key(&mut cx, "nodejs.util.inspect.custom")?;
+    let inspect = JsFunction::new(&mut cx, inspect)?;
+
+    obj.set(&mut cx, iterator_key, iterator)?;
+    obj.set(&mut cx, inspect_key, inspect)?;
+
+    Ok(obj)
+}
@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use neon::prelude::*;
+use neon::result::Throw;
+
+struct SuccessTask;
+
+impl Task for SuccessTask {
+    type Output = i32;
+    type Error = String;
+    type JsEvent = JsNumber;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        Ok(17)
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        Ok(cx.number(result.unwrap()))
+    }
+}
+
+pub fn perform_async_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+    SuccessTask.schedule(&mut cx, f);
+    Ok(cx.undefined())
+}
+
+struct FailureTask;
+
+impl Task for FailureTask {
+    type Output = i32;
+    type Error = String;
+    type JsEvent = JsNumber;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        Err(String::from("I am a failing task"))
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        cx.throw_error(&result.unwrap_err())
+    }
+}
+
+pub fn perform_failing_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+    FailureTask.schedule(&mut cx, f);
+    Ok(cx.undefined())
+}
+
+struct PanicTask;
+
+impl Task for PanicTask {
+    type Output = i32;
+    type Error = String;
+    type JsEvent = JsNumber;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        panic!("I am a panicking task")
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        Ok(cx.number(result.unwrap()))
+    }
+}
+
+pub fn perform_panicking_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+    PanicTask.schedule(&mut cx, f);
+    Ok(cx.undefined())
+}
+
+struct UnexpectedThrowTask;
+
+impl Task for UnexpectedThrowTask {
+    type Output = i32;
+    type Error = String;
+    type JsEvent = JsNumber;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        Ok(17)
+    }
+
+    fn complete(
+        self,
+        _cx: TaskContext,
+        _result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        Err(Throw)
+    }
+}
+
+pub fn perform_unexpected_throw_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+    UnexpectedThrowTask.schedule(&mut cx, f);
+    Ok(cx.undefined())
+}
+
+struct SleepTask(Duration);
+
+impl Task for SleepTask {
+    type Output = ();
+    type Error = ();
+    type JsEvent = JsUndefined;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        std::thread::sleep(self.0);
+        Ok(())
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        _result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        Ok(cx.undefined())
+    }
+}
+
+// Occupies every thread in the libuv threadpool before scheduling a task and
+// immediately cancelling it
+pub fn perform_cancelled_task(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let pool_size = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let blocked = cx.argument::<JsFunction>(1)?;
+    let f = cx.argument::<JsFunction>(2)?;
+
+    for _ in 0..pool_size {
+        SleepTask(Duration::from_millis(100)).schedule(&mut cx, blocked);
+    }
+
+    let is_cancelled = SuccessTask.schedule(&mut cx, f).cancel(&mut cx);
+
+    Ok(cx.boolean(is_cancelled))
+}
+
+pub fn task_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+    let promise = cx
+        .task(move || (1..=n).product::<u64>())
+        .promise(|mut cx, product| Ok(cx.number(product as f64)));
+
+    Ok(promise)
+}
+
+pub fn task_promise_throw(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx.task(|| "computed".to_string()).promise(|mut cx, s| {
+        cx.throw_type_error::<_, Handle<JsValue>>(format!("{} then threw", s))
+    });
+
+    Ok(promise)
+}
+
+pub fn task_promise_panic_execute(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx
+        .task(|| -> f64 { panic!("execute panicked") })
+        .promise(|mut cx, n| Ok(cx.number(n)));
+
+    Ok(promise)
+}
+
+pub fn task_promise_panic_complete(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx
+        .task(|| 1)
+        .promise(|_, _| -> JsResult<JsValue> { panic!("complete panicked") });
+
+    Ok(promise)
+}
+
+pub fn task_promise_unexpected_throw(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx
+        .task(|| 1)
+        .promise(|_, _| -> JsResult<JsValue> { Err(Throw) });
+
+    Ok(promise)
+}
@@ -1,6 +1,9 @@
 use std::cell::RefCell;
+use std::future::Future;
 use std::sync::Arc;
+use std::task::{Poll, Wake, Waker};
 
+use neon::event::{JoinError, JoinHandle};
 use neon::prelude::*;
 
 pub fn useless_root(mut cx: FunctionContext) -> JsResult<JsObject> {
@@ -185,3 +188,241 @@ pub fn drop_global_queue(mut cx: FunctionContext) -> JsResult<JsUndefined> {
 
     Ok(cx.undefined())
 }
+
+pub fn channel_throw(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let channel = cx.channel();
+
+    std::thread::spawn(move || channel.send(move |mut cx| cx.throw_error(msg)));
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_throw_with_handler(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let handler = cx.argument::<JsFunction>(1)?.root(&mut cx);
+    let mut channel = cx.channel();
+
+    channel.on_error(move |mut cx, err| {
+        let handler = handler.to_inner(&mut cx);
+        let this = cx.undefined();
+
+        handler.call(&mut cx, this, vec![err])?;
+
+        Ok(())
+    });
+
+    std::thread::spawn(move || channel.send(move |mut cx| cx.throw_error(msg)));
+
+    Ok(cx.undefined())
+}
+
+/// Minimal executor that parks the current thread until the future is ready
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = std::task::Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+fn channel_join_with<J>(mut cx: FunctionContext, join: J) -> JsResult<JsUndefined>
+where
+    J: FnOnce(JoinHandle<f64>) -> Result<f64, JoinError> + Send + 'static,
+{
+    let get_value = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+    let mut channel = cx.channel();
+
+    // Exceptions are expected; don't report them as uncaught
+    channel.on_error(|_, _| Ok(()));
+
+    std::thread::spawn(move || {
+        let handle = channel.send_joinable(move |mut cx| {
+            let get_value = get_value.into_inner(&mut cx);
+            let this = cx.undefined();
+            let args = Vec::<Handle<JsValue>>::new();
+            let value = get_value
+                .call(&mut cx, this, args)?
+                .downcast_or_throw::<JsNumber, _>(&mut cx)?;
+
+            Ok(value.value(&mut cx))
+        });
+
+        let result = join(handle);
+
+        channel.send(move |mut cx| {
+            let callback = callback.into_inner(&mut cx);
+            let this = cx.undefined();
+            let args: Vec<Handle<JsValue>> = match result {
+                Ok(n) => vec![cx.null().upcast(), cx.number(n).upcast()],
+                Err(err) => vec![cx.string(err.to_string()).upcast()],
+            };
+
+            callback.call(&mut cx, this, args)?;
+
+            Ok(())
+        });
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_join(cx: FunctionContext) -> JsResult<JsUndefined> {
+    channel_join_with(cx, |handle| handle.join())
+}
+
+pub fn channel_join_future(cx: FunctionContext) -> JsResult<JsUndefined> {
+    channel_join_with(cx, block_on)
+}
+
+pub fn channel_bounded_try_send(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let capacity = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let n = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let callback = Arc::new(cx.argument::<JsFunction>(2)?.root(&mut cx));
+    let channel = Channel::bounded(&mut cx, capacity);
+    let mut sent = 0;
+    let mut full = 0;
+
+    for i in 0..n {
+        let callback = Arc::clone(&callback);
+        let result = channel.try_send(move |mut cx| {
+            let callback = callback.to_inner(&mut cx);
+            let this = cx.undefined();
+            let args = vec![cx.number(i as f64)];
+
+            callback.call(&mut cx, this, args)?;
+
+            Ok(())
+        });
+
+        match result {
+            Ok(()) => sent += 1,
+            Err(err) if err.is_full() => full += 1,
+            Err(err) => return cx.throw_error(err.to_string()),
+        }
+    }
+
+    let result = cx.empty_array();
+    let sent = cx.number(sent as f64);
+    let full = cx.number(full as f64);
+
+    result.set(&mut cx, 0, sent)?;
+    result.set(&mut cx, 1, full)?;
+
+    Ok(result)
+}
+
+pub fn channel_bounded_stream(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let retry = cx.argument::<JsBoolean>(1)?.value(&mut cx);
+    let callback = Arc::new(cx.argument::<JsFunction>(2)?.root(&mut cx));
+    let channel = Channel::bounded(&mut cx, 1);
+
+    std::thread::spawn(move || {
+        for i in 0..n {
+            let callback = Arc::clone(&callback);
+            let mut f = move |mut cx: TaskContext| {
+                let callback = callback.to_inner(&mut cx);
+                let this = cx.undefined();
+                let args = vec![cx.number(i as f64)];
+
+                callback.call(&mut cx, this, args)?;
+
+                Ok(())
+            };
+
+            if !retry {
+                channel.send(f);
+                continue;
+            }
+
+            // Retry with the closure returned in the error until there is space
+            while let Err(err) = channel.try_send(f) {
+                assert!(err.is_full());
+                f = err.into_inner();
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+        }
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_bounded_drop_full(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let channel = Channel::bounded(&mut cx, 1);
+    let sender = channel.clone();
+
+    // Fill the queue from another thread
+    std::thread::spawn(move || {
+        sender.send(move |mut cx| {
+            let callback = callback.into_inner(&mut cx);
+            let this = cx.undefined();
+            let args = Vec::<Handle<JsValue>>::new();
+
+            callback.call(&mut cx, this, args)?;
+
+            Ok(())
+        });
+    })
+    .join()
+    .unwrap();
+
+    // Dropping a referenced channel with a full queue must not block
+    drop(channel);
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_bounded_unref_full(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let channel = Channel::bounded(&mut cx, 1);
+    let mut unreferenced = channel.clone();
+
+    unreferenced.unref(&mut cx);
+
+    // Fill the queue and drop the referenced channel while it is still full
+    std::thread::spawn(move || {
+        channel.send(|_| Ok(()));
+        drop(channel);
+    })
+    .join()
+    .unwrap();
+
+    // Keep the state alive; the event loop may only exit once the dropped
+    // channel's unref has been applied
+    std::mem::forget(unreferenced);
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_bounded_send_full(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let channel = Channel::bounded(&mut cx, 1);
+
+    channel.send(|_| Ok(()));
+    channel.send(|_| Ok(()));
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_bounded_send_joinable_full(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let channel = Channel::bounded(&mut cx, 1);
+
+    channel.send(|_| Ok(()));
+    drop(channel.send_joinable(|_| Ok(())));
+
+    Ok(cx.undefined())
+}
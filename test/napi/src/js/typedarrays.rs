@@ -0,0 +1,263 @@
+use neon::prelude::*;
+use neon::types::{DataViewElement, JsBigInt, JsDataView, JsTypedArray, Uint8Clamped};
+
+pub fn sum_f32_array(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array = cx.argument::<JsTypedArray<f32>>(0)?;
+    let sum: f32 = cx.borrow(&array, |data| data.as_slice::<f32>().iter().sum());
+
+    Ok(cx.number(sum))
+}
+
+pub fn double_f64_array(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let mut array = cx.argument::<JsTypedArray<f64>>(0)?;
+
+    cx.borrow_mut(&mut array, |data| {
+        for n in data.as_mut_slice::<f64>() {
+            *n *= 2.0;
+        }
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn get_typed_array_info(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let array = cx.argument::<JsTypedArray<u16>>(0)?;
+    let len = array.len(&mut cx);
+    let offset = array.offset(&mut cx);
+    let size = array.size(&mut cx);
+    let buffer = array.buffer(&mut cx);
+    let info = cx.empty_object();
+    let len = cx.number(len as f64);
+    let offset = cx.number(offset as f64);
+    let size = cx.number(size as f64);
+
+    info.set(&mut cx, "length", len)?;
+    info.set(&mut cx, "byteOffset", offset)?;
+    info.set(&mut cx, "byteLength", size)?;
+    info.set(&mut cx, "buffer", buffer)?;
+
+    Ok(info)
+}
+
+pub fn create_i32_array(mut cx: FunctionContext) -> JsResult<JsTypedArray<i32>> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+    JsTypedArray::new(&mut cx, len as usize)
+}
+
+pub fn create_u8_array_from_slice(mut cx: FunctionContext) -> JsResult<JsTypedArray<u8>> {
+    JsTypedArray::from_slice(&mut cx, &[1, 2, 3, 4])
+}
+
+pub fn create_clamped_array_from_slice(
+    mut cx: FunctionContext,
+) -> JsResult<JsTypedArray<Uint8Clamped>> {
+    JsTypedArray::from_slice(&mut cx, &[Uint8Clamped(0), Uint8Clamped(255)])
+}
+
+pub fn create_i64_array_from_slice(mut cx: FunctionContext) -> JsResult<JsTypedArray<i64>> {
+    JsTypedArray::from_slice(&mut cx, &[i64::MIN, -1, i64::MAX])
+}
+
+pub fn create_u64_array_from_slice(mut cx: FunctionContext) -> JsResult<JsTypedArray<u64>> {
+    JsTypedArray::from_slice(&mut cx, &[0, u64::MAX])
+}
+
+pub fn create_f32_array_from_region(mut cx: FunctionContext) -> JsResult<JsTypedArray<f32>> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx);
+    let len = cx.argument::<JsNumber>(2)?.value(&mut cx);
+
+    JsTypedArray::from_region(&mut cx, buf, offset as usize, len as usize)
+}
+
+pub fn u32_array_to_vec_sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array = cx.argument::<JsTypedArray<u32>>(0)?;
+    let sum: u32 = array.to_vec(&mut cx).into_iter().sum();
+
+    Ok(cx.number(sum))
+}
+
+macro_rules! is_typed_array {
+    ($name:ident, $t:ty) => {
+        pub fn $name(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+            let result = cx
+                .argument::<JsValue>(0)?
+                .is_a::<JsTypedArray<$t>, _>(&mut cx);
+
+            Ok(cx.boolean(result))
+        }
+    };
+}
+
+is_typed_array!(is_i8_array, i8);
+is_typed_array!(is_u8_array, u8);
+is_typed_array!(is_clamped_array, Uint8Clamped);
+is_typed_array!(is_i16_array, i16);
+is_typed_array!(is_u16_array, u16);
+is_typed_array!(is_i32_array, i32);
+is_typed_array!(is_u32_array, u32);
+is_typed_array!(is_f32_array, f32);
+is_typed_array!(is_f64_array, f64);
+is_typed_array!(is_i64_array, i64);
+is_typed_array!(is_u64_array, u64);
+
+pub fn get_data_view_info(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let size = view.size(&mut cx);
+    let offset = view.offset(&mut cx);
+    let buffer = view.buffer(&mut cx);
+    let borrowed_len = cx.borrow(&view, |data| data.len());
+    let info = cx.empty_object();
+    let size = cx.number(size as f64);
+    let offset = cx.number(offset as f64);
+    let borrowed_len = cx.number(borrowed_len as f64);
+
+    info.set(&mut cx, "byteLength", size)?;
+    info.set(&mut cx, "byteOffset", offset)?;
+    info.set(&mut cx, "borrowedLength", borrowed_len)?;
+    info.set(&mut cx, "buffer", buffer)?;
+
+    Ok(info)
+}
+
+pub fn create_data_view(mut cx: FunctionContext) -> JsResult<JsDataView> {
+    let size = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+    JsDataView::new(&mut cx, size as u32)
+}
+
+pub fn create_data_view_from_buffer(mut cx: FunctionContext) -> JsResult<JsDataView> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+
+    JsDataView::from_buffer(&mut cx, buf)
+}
+
+pub fn create_data_view_from_region(mut cx: FunctionContext) -> JsResult<JsDataView> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx);
+    let size = cx.argument::<JsNumber>(2)?.value(&mut cx);
+
+    JsDataView::from_region(&mut cx, buf, offset as usize, size as usize)
+}
+
+fn data_view_args(cx: &mut FunctionContext) -> NeonResult<(String, usize, bool)> {
+    let kind = cx.argument::<JsString>(1)?.value(cx);
+    let offset = cx.argument::<JsNumber>(2)?.value(cx) as usize;
+    let little_endian = cx.argument::<JsBoolean>(3)?.value(cx);
+
+    Ok((kind, offset, little_endian))
+}
+
+fn data_view_get<T: DataViewElement>(
+    cx: &mut FunctionContext,
+    view: Handle<JsDataView>,
+    offset: usize,
+    little_endian: bool,
+) -> Option<T> {
+    cx.borrow(&view, |data| {
+        if little_endian {
+            data.get_le::<T>(offset)
+        } else {
+            data.get_be::<T>(offset)
+        }
+    })
+}
+
+fn data_view_set<T: DataViewElement>(
+    cx: &mut FunctionContext,
+    mut view: Handle<JsDataView>,
+    offset: usize,
+    little_endian: bool,
+    value: T,
+) -> bool {
+    cx.borrow_mut(&mut view, |mut data| {
+        if little_endian {
+            data.set_le(offset, value)
+        } else {
+            data.set_be(offset, value)
+        }
+    })
+}
+
+/// Reads a value with `DataViewData`: `(view, kind, offset, littleEndian)`.
+/// Returns `undefined` if the value does not fit in the view.
+pub fn read_data_view(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let (kind, offset, le) = data_view_args(&mut cx)?;
+
+    macro_rules! number {
+        ($t:ty) => {
+            match data_view_get::<$t>(&mut cx, view, offset, le) {
+                Some(n) => cx.number(n as f64).upcast(),
+                None => cx.undefined().upcast(),
+            }
+        };
+    }
+
+    let value = match kind.as_str() {
+        "Int8" => number!(i8),
+        "Uint8" => number!(u8),
+        "Int16" => number!(i16),
+        "Uint16" => number!(u16),
+        "Int32" => number!(i32),
+        "Uint32" => number!(u32),
+        "Float32" => number!(f32),
+        "Float64" => number!(f64),
+        "BigInt64" => match data_view_get::<i64>(&mut cx, view, offset, le) {
+            Some(n) => JsBigInt::from_i64(&mut cx, n).upcast(),
+            None => cx.undefined().upcast(),
+        },
+        "BigUint64" => match data_view_get::<u64>(&mut cx, view, offset, le) {
+            Some(n) => JsBigInt::from_u64(&mut cx, n).upcast(),
+            None => cx.undefined().upcast(),
+        },
+        _ => return cx.throw_type_error("unknown kind"),
+    };
+
+    Ok(value)
+}
+
+/// Writes a value with `DataViewData`: `(view, kind, offset, littleEndian, value)`.
+/// Returns `false` if the value does not fit in the view.
+pub fn write_data_view(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let (kind, offset, le) = data_view_args(&mut cx)?;
+
+    macro_rules! number {
+        ($t:ty) => {{
+            let n = cx.argument::<JsNumber>(4)?.value(&mut cx);
+            data_view_set(&mut cx, view, offset, le, n as $t)
+        }};
+    }
+
+    macro_rules! bigint {
+        ($t:ty, $to:ident) => {{
+            let n = cx.argument::<JsBigInt>(4)?;
+            let n: $t = n.$to(&mut cx).unwrap_or_else(|err| err.into_inner());
+            data_view_set(&mut cx, view, offset, le, n)
+        }};
+    }
+
+    let written = match kind.as_str() {
+        "Int8" => number!(i8),
+        "Uint8" => number!(u8),
+        "Int16" => number!(i16),
+        "Uint16" => number!(u16),
+        "Int32" => number!(i32),
+        "Uint32" => number!(u32),
+        "Float32" => number!(f32),
+        "Float64" => number!(f64),
+        "BigInt64" => bigint!(i64, to_i64),
+        "BigUint64" => bigint!(u64, to_u64),
+        _ => return cx.throw_type_error("unknown kind"),
+    };
+
+    Ok(cx.boolean(written))
+}
+
+pub fn is_data_view(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let result = cx.argument::<JsValue>(0)?.is_a::<JsDataView, _>(&mut cx);
+
+    Ok(cx.boolean(result))
+}
@@ -66,3 +66,17 @@ pub fn strict_equals(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let eq = v1.strict_equals(&mut cx, v2);
     Ok(cx.boolean(eq))
 }
+
+pub fn instance_of(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let constructor: Handle<JsFunction> = cx.argument(1)?;
+    let result = val.instance_of(&mut cx, constructor)?;
+    Ok(cx.boolean(result))
+}
+
+pub fn get_event_type(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let constructor: Handle<JsFunction> = cx.argument(1)?;
+    let event = val.downcast_instance_or_throw(&mut cx, constructor)?;
+    event.get(&mut cx, "type")
+}
@@ -2,28 +2,40 @@ use neon::prelude::*;
 
 mod js {
     pub mod arrays;
+    pub mod bigint;
     pub mod boxed;
+    pub mod classes;
     pub mod coercions;
     pub mod date;
     pub mod errors;
     pub mod functions;
     pub mod numbers;
     pub mod objects;
+    pub mod promises;
     pub mod strings;
+    pub mod symbols;
+    pub mod tasks;
     pub mod threads;
+    pub mod typedarrays;
     pub mod types;
 }
 
 use js::arrays::*;
+use js::bigint::*;
 use js::boxed::*;
+use js::classes::*;
 use js::coercions::*;
 use js::date::*;
 use js::errors::*;
 use js::functions::*;
 use js::numbers::*;
 use js::objects::*;
+use js::promises::*;
 use js::strings::*;
+use js::symbols::*;
+use js::tasks::*;
 use js::threads::*;
+use js::typedarrays::*;
 use js::types::*;
 
 #[neon::main]
@@ -118,6 +130,13 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     cx.export_function("return_js_string", return_js_string)?;
     cx.export_function("run_string_as_script", run_string_as_script)?;
+    cx.export_function("string_to_utf16", string_to_utf16)?;
+    cx.export_function("string_from_utf16", string_from_utf16)?;
+    cx.export_function("string_to_latin1", string_to_latin1)?;
+    cx.export_function("string_from_latin1", string_from_latin1)?;
+    cx.export_function("string_utf16_len", string_utf16_len)?;
+    cx.export_function("string_read_utf16", string_read_utf16)?;
+    cx.export_function("string_read_latin1", string_read_latin1)?;
 
     cx.export_function("return_js_number", return_js_number)?;
     cx.export_function("return_large_js_number", return_large_js_number)?;
@@ -140,6 +159,13 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         "accept_and_return_negative_js_number",
         accept_and_return_negative_js_number,
     )?;
+    cx.export_function("create_integers", create_integers)?;
+    cx.export_function("number_value_i32", number_value_i32)?;
+    cx.export_function("number_value_u32", number_value_u32)?;
+    cx.export_function("number_value_i64", number_value_i64)?;
+    cx.export_function("number_value_i32_or_throw", number_value_i32_or_throw)?;
+    cx.export_function("number_value_u32_or_throw", number_value_u32_or_throw)?;
+    cx.export_function("number_value_i64_or_throw", number_value_i64_or_throw)?;
 
     cx.export_function("return_js_function", return_js_function)?;
     cx.export_function("call_js_function", call_js_function)?;
@@ -159,6 +185,9 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("read_js_array", read_js_array)?;
 
     cx.export_function("to_string", to_string)?;
+    cx.export_function("to_number", to_number)?;
+    cx.export_function("to_boolean", to_boolean)?;
+    cx.export_function("to_object", to_object)?;
 
     cx.export_function("return_js_global_object", return_js_global_object)?;
     cx.export_function("return_js_object", return_js_object)?;
@@ -207,6 +236,64 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("try_new_lossy_date", try_new_lossy_date)?;
     cx.export_function("nan_dates", nan_dates)?;
     cx.export_function("create_date_from_value", create_date_from_value)?;
+
+    cx.export_function("bigint_i64_roundtrip", bigint_i64_roundtrip)?;
+    cx.export_function("bigint_i64_lossy", bigint_i64_lossy)?;
+    cx.export_function("bigint_u64_roundtrip", bigint_u64_roundtrip)?;
+    cx.export_function("bigint_u64_lossy", bigint_u64_lossy)?;
+    cx.export_function("bigint_i128_roundtrip", bigint_i128_roundtrip)?;
+    cx.export_function("bigint_i128_lossy", bigint_i128_lossy)?;
+    cx.export_function("bigint_u128_roundtrip", bigint_u128_roundtrip)?;
+    cx.export_function("bigint_u128_lossy", bigint_u128_lossy)?;
+    cx.export_function("bigint_to_words", bigint_to_words)?;
+    cx.export_function("bigint_from_words", bigint_from_words)?;
+    cx.export_function("bigint_read_low_word", bigint_read_low_word)?;
+    cx.export_function("is_bigint", is_bigint)?;
+
+    cx.export_function("create_symbol", create_symbol)?;
+    cx.export_function(
+        "create_symbol_without_description",
+        create_symbol_without_description,
+    )?;
+    cx.export_function("get_symbol_description", get_symbol_description)?;
+    cx.export_function("get_well_known_symbol", get_well_known_symbol)?;
+    cx.export_function("get_registered_symbol", get_registered_symbol)?;
+    cx.export_function("is_symbol", is_symbol)?;
+    cx.export_function("get_symbol_property", get_symbol_property)?;
+    cx.export_function("set_symbol_property", set_symbol_property)?;
+    cx.export_function("create_counter", create_counter)?;
+
+    cx.export_function("sum_f32_array", sum_f32_array)?;
+    cx.export_function("double_f64_array", double_f64_array)?;
+    cx.export_function("get_typed_array_info", get_typed_array_info)?;
+    cx.export_function("create_i32_array", create_i32_array)?;
+    cx.export_function("create_u8_array_from_slice", create_u8_array_from_slice)?;
+    cx.export_function(
+        "create_clamped_array_from_slice",
+        create_clamped_array_from_slice,
+    )?;
+    cx.export_function("create_i64_array_from_slice", create_i64_array_from_slice)?;
+    cx.export_function("create_u64_array_from_slice", create_u64_array_from_slice)?;
+    cx.export_function("create_f32_array_from_region", create_f32_array_from_region)?;
+    cx.export_function("u32_array_to_vec_sum", u32_array_to_vec_sum)?;
+    cx.export_function("is_i8_array", is_i8_array)?;
+    cx.export_function("is_u8_array", is_u8_array)?;
+    cx.export_function("is_clamped_array", is_clamped_array)?;
+    cx.export_function("is_i16_array", is_i16_array)?;
+    cx.export_function("is_u16_array", is_u16_array)?;
+    cx.export_function("is_i32_array", is_i32_array)?;
+    cx.export_function("is_u32_array", is_u32_array)?;
+    cx.export_function("is_f32_array", is_f32_array)?;
+    cx.export_function("is_f64_array", is_f64_array)?;
+    cx.export_function("is_i64_array", is_i64_array)?;
+    cx.export_function("is_u64_array", is_u64_array)?;
+    cx.export_function("get_data_view_info", get_data_view_info)?;
+    cx.export_function("create_data_view", create_data_view)?;
+    cx.export_function("create_data_view_from_buffer", create_data_view_from_buffer)?;
+    cx.export_function("create_data_view_from_region", create_data_view_from_region)?;
+    cx.export_function("read_data_view", read_data_view)?;
+    cx.export_function("write_data_view", write_data_view)?;
+    cx.export_function("is_data_view", is_data_view)?;
     cx.export_function("create_and_get_invalid_date", create_and_get_invalid_date)?;
 
     cx.export_function("is_array", is_array)?;
@@ -220,20 +307,50 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("is_string", is_string)?;
     cx.export_function("is_undefined", is_undefined)?;
     cx.export_function("strict_equals", strict_equals)?;
+    cx.export_function("instance_of", instance_of)?;
+    cx.export_function("get_event_type", get_event_type)?;
 
     cx.export_function("new_error", new_error)?;
     cx.export_function("new_type_error", new_type_error)?;
     cx.export_function("new_range_error", new_range_error)?;
     cx.export_function("throw_error", throw_error)?;
     cx.export_function("downcast_error", downcast_error)?;
+    cx.export_function("new_error_with_code", new_error_with_code)?;
+    cx.export_function("new_error_with_cause", new_error_with_cause)?;
+    cx.export_function("new_error_with_properties", new_error_with_properties)?;
+    cx.export_function("throw_error_with_code", throw_error_with_code)?;
+    cx.export_function("read_file_or_throw", read_file_or_throw)?;
+    cx.export_function("parse_u8_or_throw", parse_u8_or_throw)?;
+    cx.export_function("load_settings_or_throw", load_settings_or_throw)?;
+    cx.export_function(
+        "load_settings_or_throw_default",
+        load_settings_or_throw_default,
+    )?;
+    cx.export_function("cyclic_error_or_throw", cyclic_error_or_throw)?;
+    cx.export_function("cyclic_error_or_throw_js", cyclic_error_or_throw_js)?;
+    cx.export_function("catch_error_details", catch_error_details)?;
+    cx.export_function("count_caught_error_sources", count_caught_error_sources)?;
 
     cx.export_function("panic", panic)?;
     cx.export_function("panic_after_throw", panic_after_throw)?;
 
     cx.export_function("throw_and_catch", throw_and_catch)?;
     cx.export_function("call_and_catch", call_and_catch)?;
+    cx.export_function("nested_try_catch", nested_try_catch)?;
+    cx.export_function("try_catch_without_throwing", try_catch_without_throwing)?;
+    cx.export_function("try_catch_after_panic", try_catch_after_panic)?;
+    cx.export_function("try_catch_finally", try_catch_finally)?;
+    cx.export_function(
+        "try_catch_finally_after_panic",
+        try_catch_finally_after_panic,
+    )?;
     cx.export_function("get_number_or_default", get_number_or_default)?;
     cx.export_function("is_construct", is_construct)?;
+    cx.export_function("make_counter", make_counter)?;
+    cx.export_function("make_throwing_closure", make_throwing_closure)?;
+    cx.export_function("make_panicking_closure", make_panicking_closure)?;
+    cx.export_function("make_dropping_closure", make_dropping_closure)?;
+    cx.export_function("count_dropped_closures", count_dropped_closures)?;
 
     fn call_get_own_property_names(mut cx: FunctionContext) -> JsResult<JsArray> {
         let object = cx.argument::<JsObject>(0)?;
@@ -242,6 +359,23 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     cx.export_function("get_own_property_names", call_get_own_property_names)?;
 
+    cx.export_function("wrap_counter", wrap_counter)?;
+    cx.export_function("increment_counter", increment_counter)?;
+    cx.export_function("is_counter", is_counter)?;
+    cx.export_function("remove_counter", remove_counter)?;
+    cx.export_function("wrap_string", wrap_string)?;
+    cx.export_function("count_finalized_counters", count_finalized_counters)?;
+    cx.export_function("define_constants", define_constants)?;
+    cx.export_function("define_property_value", define_property_value)?;
+    cx.export_function("create_temperature", create_temperature)?;
+    cx.export_function("object_key_op", object_key_op)?;
+    cx.export_function("object_has_own_handle", object_has_own_handle)?;
+    cx.export_function("object_get_prototype", object_get_prototype)?;
+    cx.export_function("object_freeze", object_freeze)?;
+    cx.export_function("object_seal", object_seal)?;
+    cx.export_function("get_property_names", get_property_names)?;
+    cx.export_function("get_entries", get_entries)?;
+
     cx.export_function("person_new", person_new)?;
     cx.export_function("person_greet", person_greet)?;
     cx.export_function("ref_person_new", ref_person_new)?;
@@ -256,7 +390,59 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("greeter_new", greeter_new)?;
     cx.export_function("greeter_greet", greeter_greet)?;
     cx.export_function("leak_channel", leak_channel)?;
+    cx.export_function("channel_throw", channel_throw)?;
+    cx.export_function("channel_throw_with_handler", channel_throw_with_handler)?;
+    cx.export_function("channel_join", channel_join)?;
+    cx.export_function("channel_join_future", channel_join_future)?;
+    cx.export_function("channel_bounded_try_send", channel_bounded_try_send)?;
+    cx.export_function("channel_bounded_stream", channel_bounded_stream)?;
+    cx.export_function("channel_bounded_drop_full", channel_bounded_drop_full)?;
+    cx.export_function("channel_bounded_unref_full", channel_bounded_unref_full)?;
+    cx.export_function("channel_bounded_send_full", channel_bounded_send_full)?;
+    cx.export_function(
+        "channel_bounded_send_joinable_full",
+        channel_bounded_send_joinable_full,
+    )?;
     cx.export_function("drop_global_queue", drop_global_queue)?;
 
+    cx.export_function("resolve_promise", resolve_promise)?;
+    cx.export_function("reject_promise", reject_promise)?;
+    cx.export_function("is_promise", is_promise)?;
+    cx.export_function("settle_promise_from_thread", settle_promise_from_thread)?;
+    cx.export_function("reject_promise_from_thread", reject_promise_from_thread)?;
+    cx.export_function(
+        "settle_promise_unexpected_throw",
+        settle_promise_unexpected_throw,
+    )?;
+    cx.export_function("retry_settle_promise", retry_settle_promise)?;
+    cx.export_function("spawn_promise", spawn_promise)?;
+    cx.export_function("spawn_promise_throw", spawn_promise_throw)?;
+    cx.export_function("spawn_promise_panic", spawn_promise_panic)?;
+    cx.export_function("spawn_promise_pending", spawn_promise_pending)?;
+    cx.export_function("leak_deferred", leak_deferred)?;
+
+    let user = User::constructor(&mut cx)?;
+    let counter = Counter::constructor(&mut cx)?;
+
+    cx.export_value("User", user)?;
+    cx.export_value("Counter", counter)?;
+
+    cx.export_function("perform_async_task", perform_async_task)?;
+    cx.export_function("perform_failing_task", perform_failing_task)?;
+    cx.export_function("perform_panicking_task", perform_panicking_task)?;
+    cx.export_function(
+        "perform_unexpected_throw_task",
+        perform_unexpected_throw_task,
+    )?;
+    cx.export_function("perform_cancelled_task", perform_cancelled_task)?;
+    cx.export_function("task_promise", task_promise)?;
+    cx.export_function("task_promise_throw", task_promise_throw)?;
+    cx.export_function("task_promise_panic_execute", task_promise_panic_execute)?;
+    cx.export_function("task_promise_panic_complete", task_promise_panic_complete)?;
+    cx.export_function(
+        "task_promise_unexpected_throw",
+        task_promise_unexpected_throw,
+    )?;
+
     Ok(())
 }